@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json};
+use tokio::sync::mpsc::Sender;
+use tracing::{error, info};
+
+use helix_beacon_client::MultiBeaconClientTrait;
+use helix_database::DatabaseService;
+use helix_datastore::Auctioneer;
+use helix_housekeeper::{Housekeeper, ProposerDutiesRefresh};
+
+use crate::admin::error::AdminApiError;
+
+/// Operator actions on relay state that is otherwise only updated on a schedule.
+pub struct AdminApi<DB, M, A>
+where
+    DB: DatabaseService + 'static,
+    M: MultiBeaconClientTrait + 'static,
+    A: Auctioneer + 'static,
+{
+    housekeeper: Arc<Housekeeper<DB, M, A>>,
+    /// Reloads the duties held in memory by the builder and proposer APIs
+    duties_refresh_sender: Sender<()>,
+}
+
+impl<DB, M, A> AdminApi<DB, M, A>
+where
+    DB: DatabaseService + 'static,
+    M: MultiBeaconClientTrait + 'static,
+    A: Auctioneer + 'static,
+{
+    pub fn new(housekeeper: Arc<Housekeeper<DB, M, A>>, duties_refresh_sender: Sender<()>) -> Self {
+        Self { housekeeper, duties_refresh_sender }
+    }
+
+    /// Refreshes proposer duties from the beacon clients without waiting for the next scheduled
+    /// update.
+    ///
+    /// Rejected while an update is already in progress.
+    pub async fn refresh_proposer_duties(
+        Extension(api): Extension<Arc<AdminApi<DB, M, A>>>,
+    ) -> Result<Json<ProposerDutiesRefresh>, AdminApiError> {
+        let refresh = api.housekeeper.refresh_proposer_duties().await?;
+
+        if let Err(err) = api.duties_refresh_sender.send(()).await {
+            error!(error = %err, "failed to reload proposer duties");
+            return Err(AdminApiError::InternalServerError);
+        }
+
+        info!(
+            head_slot = refresh.head_slot,
+            num_duties = refresh.num_duties,
+            beacon_client = %refresh.beacon_client,
+            "refreshed proposer duties",
+        );
+        Ok(Json(refresh))
+    }
+}
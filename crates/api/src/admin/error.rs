@@ -0,0 +1,49 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use helix_housekeeper::error::HousekeeperError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdminApiError {
+    #[error("proposer duties are already being refreshed")]
+    AlreadyRefreshing,
+    #[error("no head slot has been processed yet")]
+    NoHeadSlot,
+    #[error("failed to refresh proposer duties. {0}")]
+    HousekeeperError(HousekeeperError),
+    #[error("internal server error")]
+    InternalServerError,
+}
+
+impl From<HousekeeperError> for AdminApiError {
+    fn from(err: HousekeeperError) -> Self {
+        match err {
+            HousekeeperError::AlreadyUpdating(_) => AdminApiError::AlreadyRefreshing,
+            HousekeeperError::NoHeadSlot => AdminApiError::NoHeadSlot,
+            err => AdminApiError::HousekeeperError(err),
+        }
+    }
+}
+
+impl IntoResponse for AdminApiError {
+    fn into_response(self) -> Response {
+        match self {
+            AdminApiError::AlreadyRefreshing => {
+                (StatusCode::CONFLICT, "proposer duties are already being refreshed").into_response()
+            }
+            AdminApiError::NoHeadSlot => {
+                (StatusCode::SERVICE_UNAVAILABLE, "no head slot has been processed yet")
+                    .into_response()
+            }
+            AdminApiError::HousekeeperError(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to refresh proposer duties. {err}"),
+            )
+                .into_response(),
+            AdminApiError::InternalServerError => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod admin_api_tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use axum::{http::StatusCode, response::IntoResponse, Extension};
+    use helix_beacon_client::{
+        mock_multi_beacon_client::MockMultiBeaconClient, types::HeadEventData,
+        MultiBeaconClientTrait,
+    };
+    use helix_common::{chain_info::ChainInfo, RelayConfig};
+    use helix_database::MockDatabaseService;
+    use helix_datastore::MockAuctioneer;
+    use helix_housekeeper::{ChainEventUpdater, ChainUpdate, Housekeeper};
+    use tokio::sync::{broadcast, mpsc};
+
+    use crate::admin::AdminApi;
+
+    #[tokio::test]
+    async fn test_refresh_proposer_duties_updates_in_memory_duties() {
+        let proposer_duties = Arc::new(Mutex::new(Vec::new()));
+        let db = Arc::new(MockDatabaseService::new(Default::default(), proposer_duties.clone()));
+
+        let (mut chain_event_updater, slot_update_sender) =
+            ChainEventUpdater::new(
+                db.clone(),
+                MockMultiBeaconClient::default(),
+                Arc::new(ChainInfo::for_mainnet()),
+            );
+        let duties_refresh_sender = chain_event_updater.duties_refresh_sender();
+        let (head_event_sender, head_event_receiver) = broadcast::channel(10);
+        let (_payload_attributes_sender, payload_attributes_receiver) = broadcast::channel(10);
+        tokio::spawn(async move {
+            chain_event_updater.start(head_event_receiver, payload_attributes_receiver).await;
+        });
+
+        let (tx, mut rx) = mpsc::channel(10);
+        slot_update_sender.send(tx).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        head_event_sender
+            .send(HeadEventData { slot: 101, block: "0x01".to_string(), state: String::new() })
+            .unwrap();
+        match rx.recv().await {
+            Some(ChainUpdate::SlotUpdate(update)) => assert!(update.new_duties.is_none()),
+            update => panic!("expected slot update, got {update:?}"),
+        }
+
+        let beacon_client = MockMultiBeaconClient::default();
+        let housekeeper =
+            Housekeeper::new(db, beacon_client.clone(), MockAuctioneer::new(), RelayConfig::default());
+
+        // The mock beacon client sends a head event for slot 19 on subscription
+        let (housekeeper_head_sender, mut housekeeper_head_receiver) = broadcast::channel(10);
+        beacon_client.subscribe_to_head_events(housekeeper_head_sender).await;
+        let cloned_housekeeper = housekeeper.clone();
+        tokio::spawn(async move {
+            cloned_housekeeper.start(&mut housekeeper_head_receiver).await.unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let api = Arc::new(AdminApi::new(housekeeper, duties_refresh_sender));
+
+        let refresh = AdminApi::refresh_proposer_duties(Extension(api)).await.unwrap().0;
+        assert_eq!(refresh.num_duties, 1);
+        assert_eq!(refresh.beacon_client, "test_uri");
+        assert_eq!(proposer_duties.lock().unwrap().len(), 1);
+
+        // The refreshed duties are sent to subscribers without waiting for the next head event
+        let update = tokio::time::timeout(Duration::from_secs(1), rx.recv()).await.unwrap();
+        match update {
+            Some(ChainUpdate::SlotUpdate(update)) => {
+                assert_eq!(update.slot, 101);
+                let new_duties = update.new_duties.unwrap();
+                assert_eq!(new_duties.len(), 1);
+                assert_eq!(new_duties[0].slot, 19);
+            }
+            update => panic!("expected slot update, got {update:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_proposer_duties_before_first_head_event() {
+        let proposer_duties = Arc::new(Mutex::new(Vec::new()));
+        let db = Arc::new(MockDatabaseService::new(Default::default(), proposer_duties.clone()));
+        let (chain_event_updater, _slot_update_sender) = ChainEventUpdater::new(
+            db.clone(),
+            MockMultiBeaconClient::default(),
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+        let housekeeper = Housekeeper::new(
+            db,
+            MockMultiBeaconClient::default(),
+            MockAuctioneer::new(),
+            RelayConfig::default(),
+        );
+        let api =
+            Arc::new(AdminApi::new(housekeeper, chain_event_updater.duties_refresh_sender()));
+
+        let err = AdminApi::refresh_proposer_duties(Extension(api)).await.unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(proposer_duties.lock().unwrap().is_empty());
+    }
+}
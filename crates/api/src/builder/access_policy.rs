@@ -0,0 +1,210 @@
+use std::sync::RwLock;
+
+use ethereum_consensus::primitives::BlsPublicKey;
+use helix_common::{BuilderAccessConfig, BuilderAccessMode};
+use serde::Deserialize;
+
+use crate::builder::error::BuilderApiError;
+
+/// Byte offset of `builder_pubkey` within an SSZ encoded `BidTrace`.
+const BID_TRACE_BUILDER_PUBKEY_OFFSET: usize = 8 + 32 + 32;
+const BLS_PUBLIC_KEY_LENGTH: usize = 48;
+
+/// Decides which builders may submit to the relay.
+///
+/// The policy is loaded from config on startup and can be replaced at runtime through the
+/// admin API, taking effect on the next submission. Runtime updates are local to the instance
+/// that receives them.
+#[derive(Default)]
+pub struct BuilderAccessPolicy {
+    config: RwLock<BuilderAccessConfig>,
+}
+
+impl BuilderAccessPolicy {
+    pub fn new(config: BuilderAccessConfig) -> Self {
+        Self { config: RwLock::new(config) }
+    }
+
+    /// Replaces the current policy.
+    pub fn reload(&self, config: BuilderAccessConfig) {
+        *self.config.write().unwrap() = config;
+    }
+
+    pub fn config(&self) -> BuilderAccessConfig {
+        self.config.read().unwrap().clone()
+    }
+
+    pub fn is_allowed(&self, builder_pub_key: &BlsPublicKey) -> bool {
+        let config = self.config.read().unwrap();
+        match config.mode {
+            BuilderAccessMode::Open => true,
+            BuilderAccessMode::Allowlist => config.builders.contains(builder_pub_key),
+            BuilderAccessMode::Denylist => !config.builders.contains(builder_pub_key),
+        }
+    }
+
+    pub fn check(&self, builder_pub_key: &BlsPublicKey) -> Result<(), BuilderApiError> {
+        if !self.is_allowed(builder_pub_key) {
+            return Err(BuilderApiError::BuilderNotAllowed {
+                builder_pub_key: builder_pub_key.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct BidTracePeek {
+    builder_pubkey: BlsPublicKey,
+}
+
+#[derive(Deserialize)]
+struct SubmissionPeek {
+    message: BidTracePeek,
+}
+
+#[derive(Deserialize)]
+struct HeaderSubmissionMessagePeek {
+    bid_trace: BidTracePeek,
+}
+
+#[derive(Deserialize)]
+struct HeaderSubmissionPeek {
+    message: HeaderSubmissionMessagePeek,
+}
+
+fn read_builder_pubkey(body: &[u8], bid_trace_offset: usize) -> Option<BlsPublicKey> {
+    let start = bid_trace_offset.checked_add(BID_TRACE_BUILDER_PUBKEY_OFFSET)?;
+    let bytes = body.get(start..start + BLS_PUBLIC_KEY_LENGTH)?;
+    BlsPublicKey::try_from(bytes).ok()
+}
+
+/// Reads the builder public key of a `SignedBidSubmission` without decoding the payload.
+///
+/// The SSZ `BidTrace` is fixed size and comes first, so it is inlined at the start of the body.
+pub fn peek_submission_builder_pubkey(body: &[u8], is_ssz: bool) -> Option<BlsPublicKey> {
+    if is_ssz {
+        if let Some(builder_pub_key) = read_builder_pubkey(body, 0) {
+            return Some(builder_pub_key);
+        }
+    }
+    serde_json::from_slice::<SubmissionPeek>(body).ok().map(|peek| peek.message.builder_pubkey)
+}
+
+/// Reads the builder public key of a `SignedHeaderSubmission` without decoding the header.
+///
+/// The SSZ message is variable size, so the body starts with its offset. The message itself
+/// starts with the fixed size `BidTrace`.
+pub fn peek_header_submission_builder_pubkey(body: &[u8], is_ssz: bool) -> Option<BlsPublicKey> {
+    if is_ssz {
+        let message_offset = body
+            .get(..4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize);
+        if let Some(builder_pub_key) =
+            message_offset.and_then(|offset| read_builder_pubkey(body, offset))
+        {
+            return Some(builder_pub_key);
+        }
+    }
+    serde_json::from_slice::<HeaderSubmissionPeek>(body)
+        .ok()
+        .map(|peek| peek.message.bid_trace.builder_pubkey)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use ethereum_consensus::ssz::prelude::serialize;
+    use helix_common::bid_submission::{
+        v2::header_submission::{SignedHeaderSubmission, SignedHeaderSubmissionCapella},
+        BidTrace, SignedBidSubmission,
+    };
+
+    fn get_builder_pub_key(byte: u8) -> BlsPublicKey {
+        BlsPublicKey::try_from([byte; 48].as_ref()).unwrap()
+    }
+
+    fn get_config(mode: BuilderAccessMode, builders: &[BlsPublicKey]) -> BuilderAccessConfig {
+        BuilderAccessConfig { mode, builders: builders.iter().cloned().collect::<HashSet<_>>() }
+    }
+
+    fn get_bid_trace(builder_pub_key: &BlsPublicKey) -> BidTrace {
+        BidTrace { builder_public_key: builder_pub_key.clone(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_open_policy_allows_everyone() {
+        let policy = BuilderAccessPolicy::new(get_config(BuilderAccessMode::Open, &[]));
+        assert!(policy.check(&get_builder_pub_key(1)).is_ok());
+    }
+
+    #[test]
+    fn test_allowlist_policy() {
+        let allowed = get_builder_pub_key(1);
+        let policy =
+            BuilderAccessPolicy::new(get_config(BuilderAccessMode::Allowlist, &[allowed.clone()]));
+
+        assert!(policy.check(&allowed).is_ok());
+        assert!(matches!(
+            policy.check(&get_builder_pub_key(2)),
+            Err(BuilderApiError::BuilderNotAllowed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_denylist_policy() {
+        let denied = get_builder_pub_key(1);
+        let policy =
+            BuilderAccessPolicy::new(get_config(BuilderAccessMode::Denylist, &[denied.clone()]));
+
+        assert!(matches!(policy.check(&denied), Err(BuilderApiError::BuilderNotAllowed { .. })));
+        assert!(policy.check(&get_builder_pub_key(2)).is_ok());
+    }
+
+    #[test]
+    fn test_reload_takes_effect_immediately() {
+        let builder = get_builder_pub_key(1);
+        let policy = BuilderAccessPolicy::default();
+        assert!(policy.check(&builder).is_ok());
+
+        policy.reload(get_config(BuilderAccessMode::Denylist, &[builder.clone()]));
+        assert!(policy.check(&builder).is_err());
+
+        policy.reload(get_config(BuilderAccessMode::Open, &[]));
+        assert!(policy.check(&builder).is_ok());
+    }
+
+    #[test]
+    fn test_peek_submission_builder_pubkey() {
+        let builder = get_builder_pub_key(7);
+        let mut submission = SignedBidSubmission::default();
+        match &mut submission {
+            SignedBidSubmission::Deneb(submission) => submission.message = get_bid_trace(&builder),
+            SignedBidSubmission::Capella(submission) => {
+                submission.message = get_bid_trace(&builder)
+            }
+        }
+
+        let ssz_bytes = serialize(&submission).unwrap();
+        assert_eq!(peek_submission_builder_pubkey(&ssz_bytes, true), Some(builder.clone()));
+
+        let json_bytes = serde_json::to_vec(&submission).unwrap();
+        assert_eq!(peek_submission_builder_pubkey(&json_bytes, false), Some(builder));
+    }
+
+    #[test]
+    fn test_peek_header_submission_builder_pubkey() {
+        let builder = get_builder_pub_key(7);
+        let mut header = SignedHeaderSubmissionCapella::default();
+        header.message.bid_trace = get_bid_trace(&builder);
+        let header = SignedHeaderSubmission::Capella(header);
+
+        let ssz_bytes = serialize(&header).unwrap();
+        assert_eq!(peek_header_submission_builder_pubkey(&ssz_bytes, true), Some(builder.clone()));
+
+        let json_bytes = serde_json::to_vec(&header).unwrap();
+        assert_eq!(peek_header_submission_builder_pubkey(&json_bytes, false), Some(builder));
+    }
+}
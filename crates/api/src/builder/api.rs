@@ -7,7 +7,7 @@ use axum::{
     body::{to_bytes, Body},
     http::{Request, StatusCode},
     response::{IntoResponse, Response},
-    Extension,
+    Extension, Json,
 };
 use ethereum_consensus::{
     configs::mainnet::{CAPELLA_FORK_EPOCH, SECONDS_PER_SLOT},
@@ -30,7 +30,9 @@ use uuid::Uuid;
 
 use helix_common::{
     api::{
-        builder_api::{BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry},
+        builder_api::{
+            BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry, MinBidValueUpdate,
+        },
         proposer_api::ValidatorRegistrationInfo,
     },
     bid_submission::{
@@ -43,8 +45,9 @@ use helix_common::{
     signing::RelaySigningContext,
     simulator::BlockSimError,
     versioned_payload::PayloadAndBlobs,
-    BuilderInfo, GossipedHeaderTrace, GossipedPayloadTrace, HeaderSubmissionTrace,
-    SignedBuilderBid, SubmissionTrace,
+    BuilderAccessConfig, BuilderInfo, GossipedHeaderTrace, GossipedPayloadTrace,
+    HeaderSubmissionTrace, RecordedTrace, SignedBuilderBid, SubmissionTrace, TraceOutcome,
+    TraceRecord,
 };
 use helix_database::DatabaseService;
 use helix_datastore::{types::SaveBidAndUpdateTopBidResponse, Auctioneer};
@@ -52,13 +55,25 @@ use helix_housekeeper::{ChainUpdate, PayloadAttributesUpdate, SlotUpdate};
 use helix_utils::{calculate_withdrawals_root, get_payload_attributes_key, has_reached_fork, try_decode_into};
 
 use crate::{builder::{
-    error::BuilderApiError, traits::BlockSimulator, BlockSimRequest, DbInfo, OptimisticVersion,
+    access_policy::{
+        peek_header_submission_builder_pubkey, peek_submission_builder_pubkey,
+        BuilderAccessPolicy,
+    },
+    error::BuilderApiError,
+    reputation::{BuilderReputationStore, SubmissionAdmission},
+    trace_recorder::TraceRecorder,
+    traits::BlockSimulator,
+    BlockSimRequest, DbInfo, OptimisticVersion,
 }, gossiper::{
     traits::GossipClientTrait,
     types::{BroadcastHeaderParams, BroadcastPayloadParams, GossipedMessage},
-}};
+}, middleware::request_id::request_id::RequestId};
 
 pub(crate) const MAX_PAYLOAD_LENGTH: usize = 1024 * 1024 * 10;
+/// Execution clients may move the gas limit by less than `parent_gas_limit / 1024` per block.
+pub(crate) const GAS_LIMIT_ADJUSTMENT_FACTOR: u64 = 1024;
+/// Execution clients never target a gas limit below this.
+const MIN_GAS_LIMIT: u64 = 5_000;
 
 #[derive(Clone)]
 pub struct BuilderApi<A, DB, S, G>
@@ -74,6 +89,12 @@ where
     simulator: S,
     gossiper: Arc<G>,
     signing_context: Arc<RelaySigningContext>,
+    reputation: Arc<BuilderReputationStore>,
+    access_policy: Arc<BuilderAccessPolicy>,
+    trace_recorder: Arc<TraceRecorder>,
+    /// Submissions declaring a lower value are rejected before simulation. Held behind a
+    /// synchronous lock, like the access policy, as it is never held across an await.
+    min_bid_value: Arc<std::sync::RwLock<U256>>,
 
     db_sender: Sender<DbInfo>,
 
@@ -98,6 +119,10 @@ where
         simulator: S,
         gossiper: Arc<G>,
         signing_context: Arc<RelaySigningContext>,
+        reputation: Arc<BuilderReputationStore>,
+        access_policy: Arc<BuilderAccessPolicy>,
+        trace_recorder: Arc<TraceRecorder>,
+        min_bid_value: U256,
         slot_update_subscription: Sender<Sender<ChainUpdate>>,
         gossip_receiver: Receiver<GossipedMessage>,
     ) -> Self {
@@ -105,8 +130,9 @@ where
 
         // Spin up db processing task
         let db_clone = db.clone();
+        let reputation_clone = reputation.clone();
         tokio::spawn(async move {
-            process_db_additions(db_clone, db_receiver).await;
+            process_db_additions(db_clone, reputation_clone, db_receiver).await;
         });
 
         let api = Self {
@@ -116,6 +142,10 @@ where
             simulator,
             gossiper,
             signing_context,
+            reputation,
+            access_policy,
+            trace_recorder,
+            min_bid_value: Arc::new(std::sync::RwLock::new(min_bid_value)),
 
             db_sender,
 
@@ -173,10 +203,24 @@ where
     /// Implements this API: <https://flashbots.github.io/relay-specs/#/Builder/submitBlock>
     pub async fn submit_block(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        RequestId(request_id): RequestId,
         req: Request<Body>,
     ) -> Result<StatusCode, BuilderApiError> {
-        let request_id = Uuid::new_v4();
-        let mut trace = SubmissionTrace { receive: get_nanos_timestamp()?, ..Default::default() };
+        let receive = get_nanos_timestamp()?;
+        Self::submit_block_received_at(api, request_id, req, receive).await
+    }
+
+    /// `submit_block` for a request received at `receive` (ns since the unix epoch).
+    ///
+    /// Once the payload is decoded, rejected submissions are recorded in the trace recorder
+    /// alongside the accepted ones.
+    pub(crate) async fn submit_block_received_at(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        request_id: Uuid,
+        req: Request<Body>,
+        receive: u64,
+    ) -> Result<StatusCode, BuilderApiError> {
+        let mut trace = SubmissionTrace { receive, ..Default::default() };
         let (head_slot, next_duty) = api.curr_slot_info.read().await.clone();
 
         info!(
@@ -188,7 +232,36 @@ where
 
         // Decode the incoming request body into a payload
         let (payload, is_cancellations_enabled) =
-            decode_payload(req, &mut trace, &request_id).await?;
+            decode_payload(req, &api.access_policy, &mut trace, &request_id).await?;
+        trace.ms_into_slot = api.chain_info.ms_into_slot(payload.slot(), trace.receive);
+
+        let slot = payload.slot();
+        let result = Self::process_block_submission(
+            api.clone(),
+            request_id,
+            payload,
+            is_cancellations_enabled,
+            head_slot,
+            next_duty,
+            &mut trace,
+        )
+        .await;
+        if let Err(err) = &result {
+            trace.request_finish = get_nanos_timestamp().unwrap_or_default();
+            api.record_rejected_trace(&request_id, slot, err, RecordedTrace::Submission(trace));
+        }
+        result
+    }
+
+    async fn process_block_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        request_id: Uuid,
+        payload: SignedBidSubmission,
+        is_cancellations_enabled: bool,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        trace: &mut SubmissionTrace,
+    ) -> Result<StatusCode, BuilderApiError> {
         let block_hash = payload.message().block_hash.clone();
 
         // Verify that we have a validator connected for this slot
@@ -203,6 +276,7 @@ where
             builder_pub_key = ?payload.builder_public_key(),
             block_value = %payload.value(),
             block_hash = ?block_hash,
+            ms_into_slot = trace.ms_into_slot,
             "payload decoded",
         );
 
@@ -263,10 +337,21 @@ where
                 &request_id,
             )
             .await?;
+        api.check_min_bid_value(
+            payload.slot(),
+            payload.parent_hash(),
+            payload.proposer_public_key(),
+            payload.builder_public_key(),
+            payload.value(),
+            is_cancellations_enabled,
+            &request_id,
+        )
+        .await?;
         trace.floor_bid_checks = get_nanos_timestamp()?;
 
         // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        let mut builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        api.check_builder_reputation(payload.builder_public_key(), &mut builder_info, &request_id)?;
 
         // Handle trusted builders check
         if !api.check_if_trusted_builder(&next_duty, &builder_info).await {
@@ -310,7 +395,7 @@ where
         trace.pre_checks = get_nanos_timestamp()?;
 
         let (payload, was_simulated_optimistically) = api
-            .verify_submitted_block(payload, next_duty, &builder_info, &mut trace, &request_id, &payload_attributes)
+            .verify_submitted_block(payload, next_duty, &builder_info, trace, &request_id, &payload_attributes)
             .await?;
 
         // If cancellations are enabled, then abort now if there is a later submission
@@ -324,10 +409,10 @@ where
         }
 
         // Save bid to auctioneer
-        match api
+        let outcome = match api
             .save_bid_to_auctioneer(
                 &payload,
-                &mut trace,
+                trace,
                 is_cancellations_enabled,
                 floor_bid_value,
                 &request_id,
@@ -346,9 +431,13 @@ where
                     &request_id,
                 )
                 .await;
+                TraceOutcome::BidSaved
             }
-            None => { /* Bid wasn't saved so no need to gossip as it will never be served */ }
-        }
+            None => {
+                // Bid wasn't saved so no need to gossip as it will never be served
+                TraceOutcome::BidNotSaved
+            }
+        };
 
         // Log some final info
         trace.request_finish = get_nanos_timestamp()?;
@@ -358,6 +447,12 @@ where
             request_duration_ns = trace.request_finish.saturating_sub(trace.receive),
             "submit_block request finished"
         );
+        api.record_trace(
+            &request_id,
+            payload.slot(),
+            outcome,
+            RecordedTrace::Submission(trace.clone()),
+        );
 
         let optimistic_version = if was_simulated_optimistically {
             OptimisticVersion::V1
@@ -366,8 +461,9 @@ where
         };
 
         // Save submission to db.
+        let trace = Arc::new(trace.clone());
         tokio::spawn(async move {
-            if let Err(err) = api.db.store_block_submission(payload, Arc::new(trace), optimistic_version as i16).await {
+            if let Err(err) = api.db.store_block_submission(payload, trace, optimistic_version as i16).await {
                 error!(
                     error = %err,
                     "failed to store block submission",
@@ -382,9 +478,9 @@ where
     /// verifications before saving the headre to the auctioneer.
     pub async fn submit_header(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        RequestId(request_id): RequestId,
         req: Request<Body>,
     ) -> Result<StatusCode, BuilderApiError> {
-        let request_id = Uuid::new_v4();
         let mut trace =
             HeaderSubmissionTrace { receive: get_nanos_timestamp()?, ..Default::default() };
         let (head_slot, next_duty) = api.curr_slot_info.read().await.clone();
@@ -397,8 +493,37 @@ where
         );
 
         // Decode the incoming request body into a payload
-        let (mut payload, is_cancellations_enabled) =
-            decode_header_submission(req, &mut trace, &request_id).await?;
+        let (payload, is_cancellations_enabled) =
+            decode_header_submission(req, &api.access_policy, &mut trace, &request_id).await?;
+        trace.ms_into_slot = api.chain_info.ms_into_slot(payload.slot(), trace.receive);
+
+        let slot = payload.slot();
+        let result = Self::process_header_submission(
+            api.clone(),
+            request_id,
+            payload,
+            is_cancellations_enabled,
+            head_slot,
+            next_duty,
+            &mut trace,
+        )
+        .await;
+        if let Err(err) = &result {
+            trace.request_finish = get_nanos_timestamp().unwrap_or_default();
+            api.record_rejected_trace(&request_id, slot, err, RecordedTrace::HeaderSubmission(trace));
+        }
+        result
+    }
+
+    async fn process_header_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        request_id: Uuid,
+        mut payload: SignedHeaderSubmission,
+        is_cancellations_enabled: bool,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        trace: &mut HeaderSubmissionTrace,
+    ) -> Result<StatusCode, BuilderApiError> {
         let block_hash = payload.block_hash().clone();
 
         // Verify that we have a validator connected for this slot
@@ -413,6 +538,7 @@ where
             builder_pub_key = ?payload.builder_public_key(),
             block_value = %payload.value(),
             block_hash = ?block_hash,
+            ms_into_slot = trace.ms_into_slot,
             "header submission decoded",
         );
 
@@ -434,7 +560,8 @@ where
             .await?;
 
         // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        let mut builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        api.check_builder_reputation(payload.builder_public_key(), &mut builder_info, &request_id)?;
 
         // Submit header can only be processed optimistically.
         // Make sure that the builder has enough collateral to cover the submission.
@@ -540,13 +667,23 @@ where
                 &request_id,
             )
             .await?;
+        api.check_min_bid_value(
+            payload.slot(),
+            payload.parent_hash(),
+            payload.proposer_public_key(),
+            payload.builder_public_key(),
+            payload.value(),
+            is_cancellations_enabled,
+            &request_id,
+        )
+        .await?;
         trace.floor_bid_checks = get_nanos_timestamp()?;
 
         // Save bid to auctioneer
-        match api
+        let outcome = match api
             .save_header_bid_to_auctioneer(
                 payload.clone(),
-                &mut trace,
+                trace,
                 is_cancellations_enabled,
                 floor_bid_value,
                 &request_id,
@@ -562,9 +699,13 @@ where
                     &request_id,
                 )
                 .await;
+                TraceOutcome::BidSaved
             }
-            None => { /* Bid wasn't saved so no need to gossip as it will never be served */ }
-        }
+            None => {
+                // Bid wasn't saved so no need to gossip as it will never be served
+                TraceOutcome::BidNotSaved
+            }
+        };
 
         // Log some final info
         trace.request_finish = get_nanos_timestamp()?;
@@ -574,6 +715,12 @@ where
             request_duration_ns = trace.request_finish.saturating_sub(trace.receive),
             "submit_header request finished"
         );
+        api.record_trace(
+            &request_id,
+            payload.slot(),
+            outcome,
+            RecordedTrace::HeaderSubmission(trace.clone()),
+        );
 
         // Save pending block header to auctioneer
         api.auctioneer
@@ -590,8 +737,9 @@ where
 
         // Save submission to db
         let db = api.db.clone();
+        let trace = Arc::new(trace.clone());
         tokio::spawn(async move {
-            if let Err(err) = db.store_header_submission(payload, Arc::new(trace)).await {
+            if let Err(err) = db.store_header_submission(payload, trace).await {
                 error!(
                     error = %err,
                     "failed to store header submission",
@@ -615,9 +763,9 @@ where
     /// Implements this API: TODO: point to gattaca spec. rename?
     pub async fn submit_block_v2(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        RequestId(request_id): RequestId,
         req: Request<Body>,
     ) -> Result<StatusCode, BuilderApiError> {
-        let request_id = Uuid::new_v4();
         let now = SystemTime::now();
         let mut trace = SubmissionTrace { receive: get_nanos_from(now)?, ..Default::default() };
         let (head_slot, next_duty) = api.curr_slot_info.read().await.clone();
@@ -630,8 +778,45 @@ where
         );
 
         // Decode the incoming request body into a payload
-        let (payload, _) = decode_payload(req, &mut trace, &request_id).await?;
+        let (payload, is_cancellations_enabled) =
+            decode_payload(req, &api.access_policy, &mut trace, &request_id).await?;
+        api.check_min_bid_value(
+            payload.slot(),
+            payload.parent_hash(),
+            payload.proposer_public_key(),
+            payload.builder_public_key(),
+            payload.value(),
+            is_cancellations_enabled,
+            &request_id,
+        )
+        .await?;
+        trace.ms_into_slot = api.chain_info.ms_into_slot(payload.slot(), trace.receive);
+
+        let slot = payload.slot();
+        let result = Self::process_block_submission_v2(
+            api.clone(),
+            request_id,
+            payload,
+            head_slot,
+            next_duty,
+            &mut trace,
+        )
+        .await;
+        if let Err(err) = &result {
+            trace.request_finish = get_nanos_timestamp().unwrap_or_default();
+            api.record_rejected_trace(&request_id, slot, err, RecordedTrace::Submission(trace));
+        }
+        result
+    }
 
+    async fn process_block_submission_v2(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        request_id: Uuid,
+        payload: SignedBidSubmission,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        trace: &mut SubmissionTrace,
+    ) -> Result<StatusCode, BuilderApiError> {
         let builder_pub_key = payload.builder_public_key().clone();
         let block_hash = payload.message().block_hash.clone();
         debug!(
@@ -639,6 +824,7 @@ where
             builder_pub_key = ?builder_pub_key,
             block_value = %payload.value(),
             block_hash = ?payload.block_hash(),
+            ms_into_slot = trace.ms_into_slot,
             "payload decoded",
         );
 
@@ -682,7 +868,8 @@ where
             .await?;
 
         // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        let mut builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        api.check_builder_reputation(payload.builder_public_key(), &mut builder_info, &request_id)?;
 
         // submit_block_v2 can only be processed optimistically.
         // Make sure that the builder has enough collateral to cover the submission.
@@ -739,7 +926,7 @@ where
         trace.pre_checks = get_nanos_timestamp()?;
 
         let (payload, _) = match api
-            .verify_submitted_block(payload, next_duty, &builder_info, &mut trace, &request_id, &payload_attributes)
+            .verify_submitted_block(payload, next_duty, &builder_info, trace, &request_id, &payload_attributes)
             .await
         {
             Ok(val) => val,
@@ -777,10 +964,17 @@ where
             request_duration_ns = trace.request_finish.saturating_sub(trace.receive),
             "sumbit_block_v2 request finished"
         );
+        api.record_trace(
+            &request_id,
+            payload.slot(),
+            TraceOutcome::BidSaved,
+            RecordedTrace::Submission(trace.clone()),
+        );
 
         // Save submission to db
+        let trace = Arc::new(trace.clone());
         tokio::spawn(async move {
-            if let Err(err) = api.db.store_block_submission(payload, Arc::new(trace), OptimisticVersion::V2 as i16).await {
+            if let Err(err) = api.db.store_block_submission(payload, trace, OptimisticVersion::V2 as i16).await {
                 error!(
                     error = %err,
                     "failed to store block submission",
@@ -791,6 +985,33 @@ where
         Ok(StatusCode::OK)
     }
 
+    /// Replaces the builder access policy. Takes effect from the next submission on.
+    ///
+    /// Only the in-memory policy of the instance receiving the request is reloaded, the update is
+    /// not shared through the datastore. When running several relay instances, send it to each.
+    pub async fn update_builder_access(
+        Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        Json(config): Json<BuilderAccessConfig>,
+    ) -> StatusCode {
+        info!(
+            mode = ?config.mode,
+            num_builders = config.builders.len(),
+            "updating builder access policy",
+        );
+        api.access_policy.reload(config);
+        StatusCode::OK
+    }
+
+    /// Replaces the minimum bid value. Takes effect from the next submission on.
+    pub async fn update_min_bid_value(
+        Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        Json(update): Json<MinBidValueUpdate>,
+    ) -> StatusCode {
+        info!(min_bid_value = %update.min_bid_value, "updating minimum bid value");
+        *api.min_bid_value.write().unwrap() = update.min_bid_value;
+        StatusCode::OK
+    }
+
     pub async fn get_top_bid(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
         headers: HeaderMap,
@@ -1012,6 +1233,26 @@ where
         });
     }
 
+    pub(crate) fn record_trace(&self, request_id: &Uuid, slot: u64, outcome: TraceOutcome, trace: RecordedTrace) {
+        self.trace_recorder.record(TraceRecord {
+            request_id: request_id.to_string(),
+            slot,
+            outcome,
+            error: None,
+            trace,
+        });
+    }
+
+    fn record_rejected_trace(&self, request_id: &Uuid, slot: u64, err: &BuilderApiError, trace: RecordedTrace) {
+        self.trace_recorder.record(TraceRecord {
+            request_id: request_id.to_string(),
+            slot,
+            outcome: TraceOutcome::Rejected,
+            error: Some(err.to_string()),
+            trace,
+        });
+    }
+
     /// This function should be run as a seperate async task.
     /// Will process new gossiped messages from
     async fn process_gossiped_info(&self, mut recveiver: Receiver<GossipedMessage>) {
@@ -1162,6 +1403,50 @@ where
         }
     }
 
+    /// Rejects submissions declaring a value below the configured minimum. The declared value is
+    /// confirmed by simulation later on.
+    ///
+    /// If cancellations are enabled, the builder's previous bid is deleted as well, the same way
+    /// `check_if_bid_is_below_floor` handles a cancellable bid below the floor. Otherwise a
+    /// builder lowering its bid below the minimum would leave its old, higher bid live.
+    async fn check_min_bid_value(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_public_key: &BlsPublicKey,
+        builder_public_key: &BlsPublicKey,
+        value: U256,
+        is_cancellations_enabled: bool,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        let min_bid_value = *self.min_bid_value.read().unwrap();
+        if value >= min_bid_value {
+            return Ok(());
+        }
+
+        warn!(
+            request_id = %request_id,
+            value = %value,
+            min_bid_value = %min_bid_value,
+            "bid value below minimum",
+        );
+        if is_cancellations_enabled {
+            if let Err(err) = self
+                .auctioneer
+                .delete_builder_bid(slot, parent_hash, proposer_public_key, builder_public_key)
+                .await
+            {
+                error!(
+                    request_id = %request_id,
+                    error = %err,
+                    "Failed processing cancellable bid below minimum. Could not delete builder bid.",
+                );
+                return Err(BuilderApiError::InternalError);
+            }
+        }
+        Err(BuilderApiError::BidValueBelowMinimum { value, min_bid_value })
+    }
+
     /// Checks if the bid in the payload is below the floor value.
     ///
     /// - If cancellations are enabled and the bid is below the floor, it deletes the previous bid.
@@ -1281,6 +1566,7 @@ where
             registration_info.preferences,
             payload_attributes.payload_attributes.parent_beacon_block_root.clone(),
         );
+        let sim_start = Instant::now();
         let result = self
             .simulator
             .process_request(
@@ -1292,6 +1578,22 @@ where
             )
             .await;
 
+        // Only synchronous simulations tell us whether the block was valid here, optimistic ones
+        // are recorded by `process_db_additions` once they finish
+        let is_valid = match &result {
+            Ok(false) => Some(true),
+            Err(BlockSimError::BlockValidationFailed(_)) => Some(false),
+            _ => None,
+        };
+        if let Some(is_valid) = is_valid {
+            self.reputation.record_simulation(
+                payload.builder_public_key(),
+                is_valid,
+                sim_start.elapsed().as_millis() as u64,
+                get_nanos_timestamp()? / 1_000_000,
+            );
+        }
+
         match result {
             Ok(sim_optimistic) => {
                 info!(request_id = %request_id, "block simulation successful");
@@ -1306,6 +1608,9 @@ where
                     warn!(request_id = %request_id, error = %reason, "block validation failed");
                     Err(BuilderApiError::BlockValidationError(err))
                 }
+                BlockSimError::SimulationQueueTimeout => {
+                    Err(BuilderApiError::BlockValidationError(err))
+                }
                 _ => {
                     error!(request_id = %request_id, error = %err, "error simulating block");
                     Err(BuilderApiError::InternalError)
@@ -1495,6 +1800,30 @@ where
         Ok(())
     }
 
+    /// Checks the builder's reputation before processing a submission.
+    ///
+    /// Throttled builders have their submission rate capped and lose optimistic processing, so
+    /// their blocks are always simulated synchronously.
+    fn check_builder_reputation(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        builder_info: &mut BuilderInfo,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        match self.reputation.check_submission(builder_pub_key, get_nanos_timestamp()? / 1_000_000) {
+            SubmissionAdmission::Accepted => Ok(()),
+            SubmissionAdmission::Throttled => {
+                debug!(request_id = %request_id, builder_pub_key = ?builder_pub_key, "builder is throttled");
+                builder_info.is_optimistic = false;
+                Ok(())
+            }
+            SubmissionAdmission::RateLimited => {
+                warn!(request_id = %request_id, builder_pub_key = ?builder_pub_key, "throttled builder exceeded submission rate");
+                Err(BuilderApiError::BuilderThrottled { builder_pub_key: builder_pub_key.clone() })
+            }
+        }
+    }
+
     /// Fetch the builder's information. Default info is returned if fetching fails.
     async fn fetch_builder_info(&self, builder_pub_key: &BlsPublicKey) -> BuilderInfo {
         match self.auctioneer.get_builder_info(builder_pub_key).await {
@@ -1640,6 +1969,7 @@ where
 /// It returns a tuple of the decoded payload and if cancellations are enabled.
 pub async fn decode_payload(
     req: Request<Body>,
+    access_policy: &BuilderAccessPolicy,
     trace: &mut SubmissionTrace,
     request_id: &Uuid,
 ) -> Result<(SignedBidSubmission, bool), BuilderApiError> {
@@ -1694,6 +2024,11 @@ pub async fn decode_payload(
         body_bytes = buf.into();
     }
 
+    // Reject builders denied by the access policy before decoding the payload
+    if let Some(builder_pub_key) = peek_submission_builder_pubkey(&body_bytes, is_ssz) {
+        access_policy.check(&builder_pub_key)?;
+    }
+
     // Decode payload
     let payload: SignedBidSubmission = if is_ssz {
         match ssz::prelude::deserialize(&body_bytes) {
@@ -1707,6 +2042,7 @@ pub async fn decode_payload(
     } else {
         serde_json::from_slice(&body_bytes)?
     };
+    access_policy.check(payload.builder_public_key())?;
 
     trace.decode = get_nanos_timestamp()?;
     info!(
@@ -1807,6 +2143,7 @@ async fn push_top_bids<A: Auctioneer + 'static>(mut socket: WebSocket, auctionee
 /// It returns a tuple of the decoded header and if cancellations are enabled.
 pub async fn decode_header_submission(
     req: Request<Body>,
+    access_policy: &BuilderAccessPolicy,
     trace: &mut HeaderSubmissionTrace,
     request_id: &Uuid,
 ) -> Result<(SignedHeaderSubmission, bool), BuilderApiError> {
@@ -1842,6 +2179,10 @@ pub async fn decode_header_submission(
         });
     }
 
+    // Reject builders denied by the access policy before decoding the header
+    if let Some(builder_pub_key) = peek_header_submission_builder_pubkey(&body_bytes, is_ssz) {
+        access_policy.check(&builder_pub_key)?;
+    }
 
     // Decode header
     let header: SignedHeaderSubmission = if is_ssz {
@@ -1856,7 +2197,8 @@ pub async fn decode_header_submission(
     } else {
         serde_json::from_slice(&body_bytes)?
     };
-    
+    access_policy.check(header.builder_public_key())?;
+
     trace.decode = get_nanos_timestamp()?;
     info!(
         request_id = %request_id,
@@ -1872,12 +2214,27 @@ pub async fn decode_header_submission(
     Ok((header, is_cancellations_enabled))
 }
 
+/// Gas limit an execution client builds on top of `parent_gas_limit` when targeting
+/// `target_gas_limit`, following geth's `CalcGasLimit`.
+pub(crate) fn calculate_expected_gas_limit(parent_gas_limit: u64, target_gas_limit: u64) -> u64 {
+    let max_step = (parent_gas_limit / GAS_LIMIT_ADJUSTMENT_FACTOR).saturating_sub(1);
+    let target_gas_limit = target_gas_limit.max(MIN_GAS_LIMIT);
+    if parent_gas_limit < target_gas_limit {
+        (parent_gas_limit + max_step).min(target_gas_limit)
+    } else {
+        parent_gas_limit.saturating_sub(max_step).max(target_gas_limit)
+    }
+}
+
 /// - Validates the expected block.timestamp.
 /// - Ensures that the fee recipients in the payload and proposer duty match.
+/// - Ensures that the gas limit moves from the parent gas limit towards the registered one as far
+///   as a single block allows, if the parent gas limit is known.
+/// - Ensures that the gas used does not exceed the gas limit.
 /// - Ensures that the slot in the payload and payload attributes match.
 /// - Validates that the block hash in the payload and message are the same.
 /// - Validates that the parent hash in the payload and message are the same.
-fn sanity_check_block_submission(
+pub(crate) fn sanity_check_block_submission(
     payload: &impl BidSubmission,
     bid_trace: &BidTrace,
     next_duty: &BuilderGetValidatorsResponseEntry,
@@ -1900,6 +2257,27 @@ fn sanity_check_block_submission(
         });
     }
 
+    // Without the parent gas limit the check is left to the simulator
+    if let Some(parent_gas_limit) = payload_attributes.parent_gas_limit {
+        let expected_gas_limit = calculate_expected_gas_limit(
+            parent_gas_limit,
+            next_duty.entry.registration.message.gas_limit,
+        );
+        if payload.gas_limit() != expected_gas_limit {
+            return Err(BuilderApiError::IncorrectGasLimit {
+                got: payload.gas_limit(),
+                expected: expected_gas_limit,
+            });
+        }
+    }
+
+    if payload.gas_used() > payload.gas_limit() {
+        return Err(BuilderApiError::GasUsedExceedsGasLimit {
+            gas_used: payload.gas_used(),
+            gas_limit: payload.gas_limit(),
+        });
+    }
+
     if payload.slot() != next_duty.slot {
         return Err(BuilderApiError::SlotMismatch { got: payload.slot(), expected: next_duty.slot });
     }
@@ -2005,8 +2383,11 @@ fn log_save_bid_info(
 
 /// Should be called as a new async task.
 /// Stores updates to the db out of the critical path.
-async fn process_db_additions<DB: DatabaseService + 'static>(
+/// Stores everything sent on `db_receiver`, and records the outcome of optimistic simulations in
+/// the builders' reputation.
+pub(crate) async fn process_db_additions<DB: DatabaseService + 'static>(
     db: Arc<DB>,
+    reputation: Arc<BuilderReputationStore>,
     mut db_receiver: mpsc::Receiver<DbInfo>,
 ) {
     while let Some(db_info) = db_receiver.recv().await {
@@ -2044,7 +2425,30 @@ async fn process_db_additions<DB: DatabaseService + 'static>(
                     )
                 }
             }
-            DbInfo::SimulationResult { block_hash, block_sim_result } => {
+            DbInfo::SimulationResult {
+                block_hash,
+                block_sim_result,
+                builder_pub_key,
+                latency_ms,
+                is_optimistic,
+            } => {
+                // Synchronous simulations are recorded by `simulate_submission`
+                if is_optimistic {
+                    let is_valid = match &block_sim_result {
+                        Ok(()) => Some(true),
+                        Err(BlockSimError::BlockValidationFailed(_)) => Some(false),
+                        _ => None,
+                    };
+                    if let Some(is_valid) = is_valid {
+                        reputation.record_simulation(
+                            &builder_pub_key,
+                            is_valid,
+                            latency_ms,
+                            get_nanos_timestamp().unwrap_or_default() / 1_000_000,
+                        );
+                    }
+                }
+
                 if let Err(err) = db.save_simulation_result(block_hash, block_sim_result).await {
                     error!(
                         error = %err,
@@ -2368,7 +2772,8 @@ mod tests {
         let mut trace = create_test_submission_trace().await;
         let request_id = create_test_uuid().await;
 
-        let result = decode_payload(req, &mut trace, &request_id).await;
+        let result =
+            decode_payload(req, &BuilderAccessPolicy::default(), &mut trace, &request_id).await;
         match result {
             Ok(_) => panic!("Should have failed"),
             Err(err) => match err {
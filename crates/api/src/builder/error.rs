@@ -62,6 +62,18 @@ pub enum BuilderApiError {
     #[error("fee recipient mismatch. got: {got:?}, expected: {expected:?}")]
     FeeRecipientMismatch { got: ByteVector<20>, expected: ByteVector<20> },
 
+    #[error("builder {builder_pub_key:?} is not allowed to submit to this relay")]
+    BuilderNotAllowed { builder_pub_key: BlsPublicKey },
+
+    #[error("incorrect gas limit. got: {got}, expected: {expected}")]
+    IncorrectGasLimit { got: u64, expected: u64 },
+
+    #[error("gas used exceeds gas limit. gas used: {gas_used}, gas limit: {gas_limit}")]
+    GasUsedExceedsGasLimit { gas_used: u64, gas_limit: u64 },
+
+    #[error("bid value below minimum. got: {value}, min: {min_bid_value}")]
+    BidValueBelowMinimum { value: U256, min_bid_value: U256 },
+
     #[error("proposer public key mismatch. got: {got:?}, expected: {expected:?}")]
     ProposerPublicKeyMismatch { got: BlsPublicKey, expected: BlsPublicKey },
 
@@ -124,6 +136,9 @@ pub enum BuilderApiError {
     #[error("builder is not optimistic. builder_pub_key: {builder_pub_key:?}")]
     BuilderNotOptimistic { builder_pub_key: BlsPublicKey },
 
+    #[error("builder is throttled. builder_pub_key: {builder_pub_key:?}")]
+    BuilderThrottled { builder_pub_key: BlsPublicKey },
+
     #[error("builder not in proposer's trusted list: {proposer_trusted_builders:?}")]
     BuilderNotInProposersTrustedList { proposer_trusted_builders: Vec<String> },
 
@@ -205,6 +220,9 @@ impl IntoResponse for BuilderApiError {
                     BlockSimError::Timeout => {
                         (StatusCode::GATEWAY_TIMEOUT, "Block validation timeout").into_response()
                     },
+                    BlockSimError::SimulationQueueTimeout => {
+                        (StatusCode::SERVICE_UNAVAILABLE, "Simulation queue wait exceeded, submission dropped").into_response()
+                    },
                     _ => {
                         (StatusCode::BAD_REQUEST, format!("Block validation error: {err}")).into_response()
                     }
@@ -219,6 +237,18 @@ impl IntoResponse for BuilderApiError {
             BuilderApiError::FeeRecipientMismatch { got, expected } => {
                 (StatusCode::BAD_REQUEST, format!("Fee recipient mismatch. got: {got:?}, expected: {expected:?}")).into_response()
             },
+            BuilderApiError::BuilderNotAllowed { builder_pub_key } => {
+                (StatusCode::FORBIDDEN, format!("Builder {builder_pub_key:?} is not allowed to submit to this relay")).into_response()
+            },
+            BuilderApiError::IncorrectGasLimit { got, expected } => {
+                (StatusCode::BAD_REQUEST, format!("Incorrect gas limit. got: {got}, expected: {expected}")).into_response()
+            },
+            BuilderApiError::GasUsedExceedsGasLimit { gas_used, gas_limit } => {
+                (StatusCode::BAD_REQUEST, format!("Gas used exceeds gas limit. gas used: {gas_used}, gas limit: {gas_limit}")).into_response()
+            },
+            BuilderApiError::BidValueBelowMinimum { value, min_bid_value } => {
+                (StatusCode::BAD_REQUEST, format!("Bid value below minimum. got: {value}, min: {min_bid_value}")).into_response()
+            },
             BuilderApiError::SlotMismatch { got, expected } => {
                 (StatusCode::BAD_REQUEST, format!("Slot mismatch. got: {got}, expected: {expected}")).into_response()
             },
@@ -257,6 +287,9 @@ impl IntoResponse for BuilderApiError {
             BuilderApiError::BuilderNotOptimistic { builder_pub_key } => {
                 (StatusCode::BAD_REQUEST, format!("builder is not optimistic. builder_pub_key: {builder_pub_key:?}")).into_response()
             },
+            BuilderApiError::BuilderThrottled { builder_pub_key } => {
+                (StatusCode::TOO_MANY_REQUESTS, format!("builder is throttled. builder_pub_key: {builder_pub_key:?}")).into_response()
+            },
             BuilderApiError::BuilderNotInProposersTrustedList { proposer_trusted_builders } => {
                 (StatusCode::BAD_REQUEST, format!("builder not in proposer's trusted list: {proposer_trusted_builders:?}")).into_response()
             },
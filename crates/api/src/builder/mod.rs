@@ -1,7 +1,10 @@
+pub mod access_policy;
 pub mod api;
 pub mod error;
+pub mod reputation;
 pub mod simulator;
 pub mod tests;
+pub mod trace_recorder;
 pub mod types;
 
 pub use simulator::*;
@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, RwLock},
+};
+
+use ethereum_consensus::primitives::BlsPublicKey;
+use helix_common::{
+    api::data_api::{BuilderReputationResponse, BuilderReputationState},
+    BuilderReputationConfig,
+};
+
+/// Result of checking a new submission against the builder's reputation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionAdmission {
+    /// The builder is in good standing.
+    Accepted,
+    /// The builder is throttled. The submission is accepted but must be simulated synchronously.
+    Throttled,
+    /// The builder is throttled and has exceeded its submission rate.
+    RateLimited,
+}
+
+struct SimulationOutcome {
+    timestamp_ms: u64,
+    is_valid: bool,
+    latency_ms: u64,
+}
+
+/// Outcomes within the window, with running totals kept up to date as outcomes enter and leave
+/// it so re-evaluating the throttled state doesn't scan the window.
+#[derive(Default)]
+struct BuilderReputation {
+    outcomes: VecDeque<SimulationOutcome>,
+    invalid_submissions: usize,
+    total_latency_ms: u64,
+    is_throttled: bool,
+    last_throttled_submission_ms: Option<u64>,
+}
+
+impl BuilderReputation {
+    fn push(&mut self, outcome: SimulationOutcome) {
+        if !outcome.is_valid {
+            self.invalid_submissions += 1;
+        }
+        self.total_latency_ms += outcome.latency_ms;
+        self.outcomes.push_back(outcome);
+    }
+
+    /// Drops all outcomes that fell out of the window and re-evaluates the throttled state.
+    fn refresh(&mut self, config: &BuilderReputationConfig, now_ms: u64) {
+        while let Some(outcome) = self.outcomes.front() {
+            if outcome.timestamp_ms.saturating_add(config.window_ms) > now_ms {
+                break;
+            }
+            let outcome = self.outcomes.pop_front().unwrap();
+            if !outcome.is_valid {
+                self.invalid_submissions -= 1;
+            }
+            self.total_latency_ms -= outcome.latency_ms;
+        }
+
+        let total = self.outcomes.len();
+        if total == 0 || total < config.min_submissions {
+            self.is_throttled = false;
+            return;
+        }
+
+        let invalid_percentage = (self.invalid_submissions * 100 / total) as u64;
+        self.is_throttled = invalid_percentage > config.max_invalid_percentage ||
+            self.avg_simulation_latency_ms() > config.max_avg_simulation_latency_ms;
+    }
+
+    fn avg_simulation_latency_ms(&self) -> u64 {
+        if self.outcomes.is_empty() {
+            return 0;
+        }
+        self.total_latency_ms / self.outcomes.len() as u64
+    }
+}
+
+/// Tracks rolling simulation outcomes per builder and throttles builders that consistently
+/// submit invalid or slow blocks.
+///
+/// Throttled builders have their submission rate capped and are always simulated synchronously.
+/// A builder returns to good standing once its outcomes within the window are back under the
+/// configured thresholds.
+///
+/// Each builder has its own lock, the map is only locked for writing to add a new builder.
+pub struct BuilderReputationStore {
+    config: BuilderReputationConfig,
+    builders: RwLock<HashMap<BlsPublicKey, Mutex<BuilderReputation>>>,
+}
+
+impl BuilderReputationStore {
+    pub fn new(config: BuilderReputationConfig) -> Self {
+        Self { config, builders: RwLock::new(HashMap::new()) }
+    }
+
+    /// Runs `f` on the reputation of `builder_pub_key`, if it has any.
+    fn with_reputation<T>(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        f: impl FnOnce(&mut BuilderReputation) -> T,
+    ) -> Option<T> {
+        let builders = self.builders.read().unwrap();
+        let mut reputation = builders.get(builder_pub_key)?.lock().unwrap();
+        Some(f(&mut reputation))
+    }
+
+    /// Records the outcome of a simulation for `builder_pub_key`.
+    pub fn record_simulation(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        is_valid: bool,
+        latency_ms: u64,
+        now_ms: u64,
+    ) {
+        let record = |reputation: &mut BuilderReputation| {
+            reputation.push(SimulationOutcome { timestamp_ms: now_ms, is_valid, latency_ms });
+            reputation.refresh(&self.config, now_ms);
+        };
+        if self.with_reputation(builder_pub_key, &record).is_some() {
+            return;
+        }
+
+        let mut builders = self.builders.write().unwrap();
+        let reputation = builders.entry(builder_pub_key.clone()).or_default();
+        record(reputation.get_mut().unwrap());
+    }
+
+    /// Checks whether a new submission from `builder_pub_key` should be accepted.
+    pub fn check_submission(&self, builder_pub_key: &BlsPublicKey, now_ms: u64) -> SubmissionAdmission {
+        self.with_reputation(builder_pub_key, |reputation| {
+            reputation.refresh(&self.config, now_ms);
+            if !reputation.is_throttled {
+                return SubmissionAdmission::Accepted;
+            }
+
+            if let Some(last_submission_ms) = reputation.last_throttled_submission_ms {
+                if now_ms.saturating_sub(last_submission_ms) <
+                    self.config.throttled_submission_interval_ms
+                {
+                    return SubmissionAdmission::RateLimited;
+                }
+            }
+            reputation.last_throttled_submission_ms = Some(now_ms);
+
+            SubmissionAdmission::Throttled
+        })
+        .unwrap_or(SubmissionAdmission::Accepted)
+    }
+
+    /// Returns the current reputation of `builder_pub_key`.
+    pub fn status(&self, builder_pub_key: &BlsPublicKey, now_ms: u64) -> BuilderReputationResponse {
+        let mut response = BuilderReputationResponse {
+            builder_pubkey: builder_pub_key.clone(),
+            state: BuilderReputationState::Good,
+            valid_submissions: 0,
+            invalid_submissions: 0,
+            avg_simulation_latency_ms: 0,
+        };
+
+        self.with_reputation(builder_pub_key, |reputation| {
+            reputation.refresh(&self.config, now_ms);
+            response.state = match reputation.is_throttled {
+                true => BuilderReputationState::Throttled,
+                false => BuilderReputationState::Good,
+            };
+            response.valid_submissions = reputation.outcomes.len() - reputation.invalid_submissions;
+            response.invalid_submissions = reputation.invalid_submissions;
+            response.avg_simulation_latency_ms = reputation.avg_simulation_latency_ms();
+        });
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_config() -> BuilderReputationConfig {
+        BuilderReputationConfig {
+            window_ms: 10_000,
+            min_submissions: 4,
+            max_invalid_percentage: 50,
+            max_avg_simulation_latency_ms: 1_000,
+            throttled_submission_interval_ms: 500,
+        }
+    }
+
+    #[test]
+    fn test_unknown_builder_is_in_good_standing() {
+        let store = BuilderReputationStore::new(get_config());
+        let builder = BlsPublicKey::default();
+
+        assert_eq!(store.check_submission(&builder, 0), SubmissionAdmission::Accepted);
+        assert_eq!(store.status(&builder, 0).state, BuilderReputationState::Good);
+    }
+
+    #[test]
+    fn test_invalid_submissions_throttle_builder_until_window_elapses() {
+        let store = BuilderReputationStore::new(get_config());
+        let builder = BlsPublicKey::default();
+
+        store.record_simulation(&builder, true, 100, 1_000);
+        store.record_simulation(&builder, false, 100, 1_100);
+        store.record_simulation(&builder, false, 100, 1_200);
+        assert_eq!(store.check_submission(&builder, 1_300), SubmissionAdmission::Accepted);
+
+        store.record_simulation(&builder, false, 100, 1_300);
+        let status = store.status(&builder, 1_400);
+        assert_eq!(status.state, BuilderReputationState::Throttled);
+        assert_eq!(status.valid_submissions, 1);
+        assert_eq!(status.invalid_submissions, 3);
+
+        // Throttled builders are rate limited
+        assert_eq!(store.check_submission(&builder, 1_400), SubmissionAdmission::Throttled);
+        assert_eq!(store.check_submission(&builder, 1_500), SubmissionAdmission::RateLimited);
+        assert_eq!(store.check_submission(&builder, 1_900), SubmissionAdmission::Throttled);
+
+        // Back in good standing once the outcomes leave the window
+        assert_eq!(store.check_submission(&builder, 11_300), SubmissionAdmission::Accepted);
+        assert_eq!(store.status(&builder, 11_300).state, BuilderReputationState::Good);
+    }
+
+    #[test]
+    fn test_slow_simulations_throttle_builder() {
+        let store = BuilderReputationStore::new(get_config());
+        let builder = BlsPublicKey::default();
+
+        for i in 0..4 {
+            store.record_simulation(&builder, true, 1_500, i * 100);
+        }
+
+        let status = store.status(&builder, 400);
+        assert_eq!(status.state, BuilderReputationState::Throttled);
+        assert_eq!(status.avg_simulation_latency_ms, 1_500);
+        assert_eq!(store.check_submission(&builder, 400), SubmissionAdmission::Throttled);
+    }
+
+    #[test]
+    fn test_outcomes_leaving_the_window_update_totals() {
+        let store = BuilderReputationStore::new(get_config());
+        let builder = BlsPublicKey::default();
+
+        store.record_simulation(&builder, false, 3_000, 0);
+        store.record_simulation(&builder, true, 100, 5_000);
+        store.record_simulation(&builder, true, 200, 6_000);
+
+        let status = store.status(&builder, 9_000);
+        assert_eq!(status.invalid_submissions, 1);
+        assert_eq!(status.avg_simulation_latency_ms, 1_100);
+
+        // The first outcome leaves the window
+        let status = store.status(&builder, 10_000);
+        assert_eq!(status.valid_submissions, 2);
+        assert_eq!(status.invalid_submissions, 0);
+        assert_eq!(status.avg_simulation_latency_ms, 150);
+    }
+}
@@ -2,6 +2,7 @@ pub mod mock_simulator;
 pub mod optimistic_simulator;
 mod optimistic_simulator_tests;
 pub mod rpc_simulator;
+pub mod simulation_queue;
 mod simulator_tests;
 pub mod traits;
 
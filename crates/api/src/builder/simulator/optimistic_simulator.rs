@@ -12,7 +12,8 @@ use helix_database::DatabaseService;
 use helix_datastore::Auctioneer;
 
 use crate::builder::{
-    rpc_simulator::RpcSimulator, traits::BlockSimulator, BlockSimRequest, DbInfo,
+    rpc_simulator::RpcSimulator, simulation_queue::SimulationQueue, traits::BlockSimulator,
+    BlockSimRequest, DbInfo,
 };
 
 /// OptimisticSimulator is responsible for running simulations optimistically or synchronously based
@@ -32,8 +33,14 @@ pub struct OptimisticSimulator<A: Auctioneer + 'static, DB: DatabaseService + 's
 }
 
 impl<A: Auctioneer + 'static, DB: DatabaseService + 'static> OptimisticSimulator<A, DB> {
-    pub fn new(auctioneer: Arc<A>, db: Arc<DB>, http: Client, endpoint: String) -> Self {
-        let simulator = Arc::new(RpcSimulator::new(http, endpoint));
+    pub fn new(
+        auctioneer: Arc<A>,
+        db: Arc<DB>,
+        http: Client,
+        endpoint: String,
+        simulation_queue: Option<Arc<SimulationQueue>>,
+    ) -> Self {
+        let simulator = Arc::new(RpcSimulator::new(http, endpoint, simulation_queue));
         let failsafe_triggered = Arc::new(RwLock::new(false));
         Self { simulator, auctioneer, db, failsafe_triggered }
     }
@@ -51,7 +58,7 @@ impl<A: Auctioneer + 'static, DB: DatabaseService + 'static> OptimisticSimulator
     /// Handle simulation of request.
     ///
     /// If the simulation fails and the builder is optimistic, it will be demoted.
-    /// The simulation result will be written to the db in `self.simulator.process_request`
+    /// The simulation result will be written to the db in `self.simulator.simulate`
     async fn handle_simulation(
         &self,
         request: BlockSimRequest,
@@ -59,16 +66,11 @@ impl<A: Auctioneer + 'static, DB: DatabaseService + 'static> OptimisticSimulator
         sim_result_saver_sender: Sender<DbInfo>,
         builder_info: BuilderInfo,
         request_id: Uuid,
+        is_optimistic: bool,
     ) -> Result<(), BlockSimError> {
         if let Err(err) = self
             .simulator
-            .process_request(
-                request.clone(),
-                &builder_info,
-                is_top_bid,
-                sim_result_saver_sender,
-                request_id,
-            )
+            .simulate(request.clone(), is_top_bid, sim_result_saver_sender, request_id, is_optimistic)
             .await
         {
             if let BlockSimError::BlockValidationFailed(_) = err {
@@ -189,6 +191,7 @@ impl<A: Auctioneer, DB: DatabaseService> BlockSimulator for OptimisticSimulator<
                         sim_result_saver_sender,
                         builder_info,
                         request_id,
+                        true,
                     )
                     .await
             });
@@ -209,6 +212,7 @@ impl<A: Auctioneer, DB: DatabaseService> BlockSimulator for OptimisticSimulator<
                 sim_result_saver_sender,
                 builder_info.clone(),
                 request_id,
+                false,
             )
             .await
             .map(|_| false)
@@ -2,8 +2,11 @@
 mod simulator_tests {
     // ++++ IMPORTS ++++
     use crate::builder::{
+        api::process_db_additions,
         optimistic_simulator::OptimisticSimulator,
+        reputation::BuilderReputationStore,
         rpc_simulator::{BlockSimRpcResponse, JsonRpcError},
+        simulation_queue::SimulationQueue,
         traits::BlockSimulator,
         BlockSimRequest,
     };
@@ -15,15 +18,18 @@ mod simulator_tests {
     use helix_common::{
         bid_submission::{BidTrace, SignedBidSubmission, SignedBidSubmissionCapella},
         simulator::BlockSimError,
-        BuilderInfo, ValidatorPreferences,
+        BuilderInfo, SimulationQueueConfig, SimulationQueuePolicy, ValidatorPreferences,
     };
     use helix_database::MockDatabaseService;
-    use helix_datastore::MockAuctioneer;
+    use helix_datastore::{Auctioneer, MockAuctioneer};
     use rand::Rng;
     use reqwest::Client;
     use reth_primitives::hex;
     use serde_json::json;
-    use std::sync::{atomic::AtomicBool, Arc};
+    use std::{
+        sync::{atomic::AtomicBool, Arc},
+        time::Duration,
+    };
     use uuid::Uuid;
 
     // ++++ HELPERS ++++
@@ -38,7 +44,26 @@ mod simulator_tests {
         auctioneer.builder_demoted = builder_demoted;
         let db =
             MockDatabaseService::new(Arc::new(Default::default()), Arc::new(Default::default()));
-        OptimisticSimulator::new(Arc::new(auctioneer), Arc::new(db), http, endpoint.to_string())
+        OptimisticSimulator::new(Arc::new(auctioneer), Arc::new(db), http, endpoint.to_string(), None)
+    }
+
+    fn get_optimistic_simulator_with_auctioneer(
+        endpoint: &str,
+        builder_info: BuilderInfo,
+    ) -> (OptimisticSimulator<MockAuctioneer, MockDatabaseService>, Arc<MockAuctioneer>) {
+        let mut auctioneer = MockAuctioneer::new();
+        auctioneer.builder_info = Some(builder_info);
+        let auctioneer = Arc::new(auctioneer);
+        let db =
+            MockDatabaseService::new(Arc::new(Default::default()), Arc::new(Default::default()));
+        let simulator = OptimisticSimulator::new(
+            auctioneer.clone(),
+            Arc::new(db),
+            Client::new(),
+            endpoint.to_string(),
+            None,
+        );
+        (simulator, auctioneer)
     }
 
     fn get_byte_vector_32_for_hex(hex: &str) -> ByteVector<32> {
@@ -144,6 +169,51 @@ mod simulator_tests {
         assert!(builder_demoted.load(std::sync::atomic::Ordering::Relaxed));
     }
 
+    #[tokio::test]
+    async fn test_optimistic_simulation_failure_is_recorded_in_reputation() {
+        let rpc_response = BlockSimRpcResponse {
+            error: Some(JsonRpcError { message: "validation failed".to_string() }),
+        };
+        let mut server = mockito::Server::new();
+        let mock =
+            server.mock("POST", "/").with_status(200).with_body(json!(rpc_response).to_string()).create();
+
+        let reputation = Arc::new(BuilderReputationStore::new(Default::default()));
+        let (sim_res_sender, sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        tokio::spawn(process_db_additions(
+            Arc::new(MockDatabaseService::default()),
+            reputation.clone(),
+            sim_res_receiver,
+        ));
+
+        let builder_info =
+            BuilderInfo { collateral: U256::from(100), is_optimistic: true, builder_id: None };
+        let simulator = get_optimistic_simulator(
+            &server.url(),
+            Some(builder_info.clone()),
+            Arc::new(AtomicBool::new(false)),
+        );
+
+        // The submission returns before the simulation ran
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Ok(true)));
+
+        // give the simulator time to process the request
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        mock.assert();
+        let builder = BlsPublicKey::try_from(&get_test_pub_key_bytes(false)[..]).unwrap();
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let status = reputation.status(&builder, now_ms);
+        assert_eq!(status.valid_submissions, 0);
+        assert_eq!(status.invalid_submissions, 1);
+    }
+
     #[tokio::test]
     async fn test_process_request_non_optimistically_ok() {
         let mut server = mockito::Server::new();
@@ -206,4 +276,140 @@ mod simulator_tests {
         assert!(matches!(result.unwrap_err(), BlockSimError::BlockValidationFailed(_)));
         assert!(!builder_demoted.load(std::sync::atomic::Ordering::Relaxed));
     }
+
+    #[tokio::test]
+    async fn test_optimistic_accept_then_fail_requires_sync_simulation() {
+        let rpc_response = BlockSimRpcResponse {
+            error: Some(JsonRpcError { message: "validation failed".to_string() }),
+        };
+        let rpc_response_json = json!(rpc_response).to_string();
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(rpc_response_json)
+            .expect(2)
+            .create();
+
+        let builder_info =
+            BuilderInfo { collateral: U256::from(100), is_optimistic: true, builder_id: None };
+        let (simulator, auctioneer) =
+            get_optimistic_simulator_with_auctioneer(&server.url(), builder_info.clone());
+        let builder_pub_key = BlsPublicKey::try_from(&get_test_pub_key_bytes(false)[..]).unwrap();
+
+        // First bid is accepted before the simulation completes
+        let (sim_res_sender, _sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Ok(true)));
+
+        // give the simulator time to process the request
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // The failed simulation demotes the builder
+        let builder_info = auctioneer.get_builder_info(&builder_pub_key).await.unwrap();
+        assert!(!builder_info.is_optimistic);
+
+        // Subsequent bids are simulated synchronously and the failure is surfaced
+        let (sim_res_sender, _sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+
+        mock.assert();
+        assert!(matches!(result, Err(BlockSimError::BlockValidationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_optimistic_accept_then_succeed_stays_optimistic() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":"1","result":true}"#)
+            .expect(2)
+            .create();
+
+        let builder_info =
+            BuilderInfo { collateral: U256::from(100), is_optimistic: true, builder_id: None };
+        let (simulator, auctioneer) =
+            get_optimistic_simulator_with_auctioneer(&server.url(), builder_info.clone());
+        let builder_pub_key = BlsPublicKey::try_from(&get_test_pub_key_bytes(false)[..]).unwrap();
+
+        // First bid is accepted before the simulation completes
+        let (sim_res_sender, _sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Ok(true)));
+
+        // give the simulator time to process the request
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // The builder keeps its optimistic status and the next bid is accepted optimistically too
+        let builder_info = auctioneer.get_builder_info(&builder_pub_key).await.unwrap();
+        assert!(builder_info.is_optimistic);
+
+        let (sim_res_sender, _sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Ok(true)));
+
+        // give the simulator time to process the request
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        mock.assert();
+        assert!(!auctioneer.builder_demoted.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_queued_optimistic_simulation_is_not_dropped() {
+        let rpc_response = BlockSimRpcResponse {
+            error: Some(JsonRpcError { message: "validation failed".to_string() }),
+        };
+        let rpc_response_json = json!(rpc_response).to_string();
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/").with_status(200).with_body(rpc_response_json).create();
+
+        let queue = Arc::new(SimulationQueue::new(&SimulationQueueConfig {
+            max_concurrent_simulations: 1,
+            policy: SimulationQueuePolicy::Fifo,
+            max_wait_ms: 50,
+        }));
+        let builder_demoted = Arc::new(AtomicBool::new(false));
+        let builder_info =
+            BuilderInfo { collateral: U256::from(100), is_optimistic: true, builder_id: None };
+        let mut auctioneer = MockAuctioneer::new();
+        auctioneer.builder_info = Some(builder_info.clone());
+        auctioneer.builder_demoted = builder_demoted.clone();
+        let db =
+            MockDatabaseService::new(Arc::new(Default::default()), Arc::new(Default::default()));
+        let simulator = OptimisticSimulator::new(
+            Arc::new(auctioneer),
+            Arc::new(db),
+            Client::new(),
+            server.url(),
+            Some(queue.clone()),
+        );
+
+        // Every simulation slot stays taken for longer than the queue's max wait
+        let permit = queue.acquire(U256::ZERO).await.unwrap();
+        let (sim_res_sender, _sim_res_receiver) = tokio::sync::mpsc::channel(100);
+        let result = simulator
+            .process_request(get_sim_req(), &builder_info, true, sim_res_sender, Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Ok(true)));
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(!builder_demoted.load(std::sync::atomic::Ordering::Relaxed));
+
+        // The optimistic simulation still runs once a slot frees up and demotes the builder
+        drop(permit);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        mock.assert();
+        assert!(builder_demoted.load(std::sync::atomic::Ordering::Relaxed));
+    }
 }
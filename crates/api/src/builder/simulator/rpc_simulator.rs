@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use helix_common::BuilderInfo;
 use reqwest::{
@@ -5,13 +7,15 @@ use reqwest::{
     Client, Response, StatusCode,
 };
 use serde_json::json;
-use tokio::sync::mpsc::Sender;
-use tracing::{debug, error, info};
+use tokio::{sync::mpsc::Sender, time::Instant};
+use tracing::{debug, error, info, warn};
 
 use helix_common::simulator::BlockSimError;
 use uuid::Uuid;
 
-use crate::builder::{traits::BlockSimulator, BlockSimRequest, DbInfo};
+use crate::builder::{
+    simulation_queue::SimulationQueue, traits::BlockSimulator, BlockSimRequest, DbInfo,
+};
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct JsonRpcError {
@@ -25,15 +29,18 @@ pub struct BlockSimRpcResponse {
 
 /// RpcSimulator is responsible for sending block requests to the RPC endpoint for validation.
 /// It uses the `flashbots_validateBuilderSubmissionV2` method for the actual validation.
+///
+/// If a `queue` is set, requests wait for a free slot in it before being sent.
 #[derive(Clone)]
 pub struct RpcSimulator {
     http: Client,
     endpoint: String,
+    queue: Option<Arc<SimulationQueue>>,
 }
 
 impl RpcSimulator {
-    pub fn new(http: Client, endpoint: String) -> Self {
-        Self { http, endpoint }
+    pub fn new(http: Client, endpoint: String, queue: Option<Arc<SimulationQueue>>) -> Self {
+        Self { http, endpoint, queue }
     }
 
     /// Sends an RPC request for block validation.
@@ -84,33 +91,53 @@ impl RpcSimulator {
             Err(err) => Err(BlockSimError::RpcError(err.to_string())),
         }
     }
-}
 
-#[async_trait]
-impl BlockSimulator for RpcSimulator {
-    async fn process_request(
+    /// Simulates the request once a slot in the queue is free.
+    ///
+    /// Optimistic simulations wait for a slot however long it takes, as their bid is already live
+    /// and a dropped simulation would leave an invalid bid unchecked.
+    pub async fn simulate(
         &self,
         request: BlockSimRequest,
-        _builder_info: &BuilderInfo,
         is_top_bid: bool,
         sim_result_saver_sender: Sender<DbInfo>,
         request_id: Uuid,
+        is_optimistic: bool,
     ) -> Result<bool, BlockSimError> {
         let block_hash = request.execution_payload.block_hash().clone();
+        let builder_pub_key = request.message.builder_public_key.clone();
         debug!(
             request_id = %request_id,
             block_hash = %block_hash,
             builder_pub_key = %request.message.builder_public_key,
-            "RpcSimulator::process_request",
+            "RpcSimulator::simulate",
         );
 
+        let _permit = match &self.queue {
+            Some(queue) if is_optimistic => Some(queue.acquire_unbounded(request.message.value).await?),
+            Some(queue) => match queue.acquire(request.message.value).await {
+                Ok(permit) => Some(permit),
+                Err(err) => {
+                    warn!(request_id = %request_id, block_hash = %block_hash, "simulation queued for too long, dropping");
+                    return Err(err);
+                }
+            },
+            None => None,
+        };
+
+        let sim_start = Instant::now();
         match self.send_rpc_request(request, is_top_bid).await {
             Ok(response) => {
                 let result = Self::process_rpc_response(response).await;
 
                 // Send sim result to db processor task
-                let db_info =
-                    DbInfo::SimulationResult { block_hash, block_sim_result: result.clone() };
+                let db_info = DbInfo::SimulationResult {
+                    block_hash,
+                    block_sim_result: result.clone(),
+                    builder_pub_key,
+                    latency_ms: sim_start.elapsed().as_millis() as u64,
+                    is_optimistic,
+                };
                 sim_result_saver_sender
                     .send(db_info)
                     .await
@@ -125,3 +152,17 @@ impl BlockSimulator for RpcSimulator {
         }
     }
 }
+
+#[async_trait]
+impl BlockSimulator for RpcSimulator {
+    async fn process_request(
+        &self,
+        request: BlockSimRequest,
+        _builder_info: &BuilderInfo,
+        is_top_bid: bool,
+        sim_result_saver_sender: Sender<DbInfo>,
+        request_id: Uuid,
+    ) -> Result<bool, BlockSimError> {
+        self.simulate(request, is_top_bid, sim_result_saver_sender, request_id, false).await
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use ethereum_consensus::primitives::U256;
+use helix_common::{simulator::BlockSimError, SimulationQueueConfig, SimulationQueuePolicy};
+use tokio::sync::oneshot;
+
+/// Bounds the number of in-flight simulations.
+///
+/// Once all slots are taken, simulations wait in a queue ordered by the configured policy. A
+/// simulation that waits longer than `max_wait` is dropped, as its slot is likely over by the time
+/// it would run. Optimistic simulations are never dropped, see `acquire_unbounded`.
+pub struct SimulationQueue {
+    policy: SimulationQueuePolicy,
+    max_wait: Duration,
+    state: Mutex<QueueState>,
+}
+
+struct QueueState {
+    available: usize,
+    waiters: BinaryHeap<Waiter>,
+    next_seq: u64,
+}
+
+struct Waiter {
+    /// Zero for every waiter under the FIFO policy.
+    priority: U256,
+    seq: u64,
+    sender: oneshot::Sender<()>,
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+/// Frees its slot in the queue when dropped.
+pub struct SimulationPermit {
+    queue: Arc<SimulationQueue>,
+}
+
+impl Drop for SimulationPermit {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+/// A simulation waiting in the queue.
+///
+/// If dropped while waiting, e.g. because the request was cancelled, a slot already handed over to
+/// it is released again.
+struct QueuedWaiter {
+    queue: Arc<SimulationQueue>,
+    receiver: oneshot::Receiver<()>,
+    done: bool,
+}
+
+impl QueuedWaiter {
+    fn into_permit(mut self) -> SimulationPermit {
+        self.done = true;
+        SimulationPermit { queue: self.queue.clone() }
+    }
+
+    /// Stops waiting, returning a permit if the slot was handed over in the meantime.
+    fn cancel(mut self) -> Option<SimulationPermit> {
+        self.receiver.close();
+        match self.receiver.try_recv() {
+            Ok(()) => Some(self.into_permit()),
+            Err(_) => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl Drop for QueuedWaiter {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        self.receiver.close();
+        if self.receiver.try_recv().is_ok() {
+            self.queue.release();
+        }
+    }
+}
+
+impl SimulationQueue {
+    pub fn new(config: &SimulationQueueConfig) -> Self {
+        Self {
+            policy: config.policy,
+            max_wait: Duration::from_millis(config.max_wait_ms),
+            state: Mutex::new(QueueState {
+                available: config.max_concurrent_simulations,
+                waiters: BinaryHeap::new(),
+                next_seq: 0,
+            }),
+        }
+    }
+
+    /// Waits for a free simulation slot. `value` is the declared bid value, used for ordering under
+    /// the `HighestValue` policy.
+    pub async fn acquire(self: &Arc<Self>, value: U256) -> Result<SimulationPermit, BlockSimError> {
+        self.acquire_within(value, Some(self.max_wait)).await
+    }
+
+    /// Waits for a free simulation slot without giving up after `max_wait`.
+    ///
+    /// Used for optimistic simulations, whose bids are already live in the auction and must be
+    /// checked however long the queue is.
+    pub async fn acquire_unbounded(
+        self: &Arc<Self>,
+        value: U256,
+    ) -> Result<SimulationPermit, BlockSimError> {
+        self.acquire_within(value, None).await
+    }
+
+    async fn acquire_within(
+        self: &Arc<Self>,
+        value: U256,
+        max_wait: Option<Duration>,
+    ) -> Result<SimulationPermit, BlockSimError> {
+        let mut waiter = {
+            let mut state = self.state.lock().unwrap();
+            if state.available > 0 && state.waiters.is_empty() {
+                state.available -= 1;
+                return Ok(SimulationPermit { queue: self.clone() });
+            }
+
+            let priority = match self.policy {
+                SimulationQueuePolicy::Fifo => U256::ZERO,
+                SimulationQueuePolicy::HighestValue => value,
+            };
+            let (sender, receiver) = oneshot::channel();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiters.push(Waiter { priority, seq, sender });
+            QueuedWaiter { queue: self.clone(), receiver, done: false }
+        };
+
+        let Some(max_wait) = max_wait else {
+            // Senders are only dropped once the slot was handed over
+            return match (&mut waiter.receiver).await {
+                Ok(()) => Ok(waiter.into_permit()),
+                Err(_) => Err(BlockSimError::SimulationQueueTimeout),
+            };
+        };
+
+        match tokio::time::timeout(max_wait, &mut waiter.receiver).await {
+            Ok(Ok(())) => Ok(waiter.into_permit()),
+            // The slot may have been handed over just as the wait expired
+            _ => waiter.cancel().ok_or(BlockSimError::SimulationQueueTimeout),
+        }
+    }
+
+    /// Hands the freed slot to the next waiter still queued, or makes it available.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        while let Some(waiter) = state.waiters.pop() {
+            if waiter.sender.send(()).is_ok() {
+                return;
+            }
+        }
+        state.available += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_queue(policy: SimulationQueuePolicy, max_wait_ms: u64) -> Arc<SimulationQueue> {
+        Arc::new(SimulationQueue::new(&SimulationQueueConfig {
+            max_concurrent_simulations: 1,
+            policy,
+            max_wait_ms,
+        }))
+    }
+
+    /// Queues one simulation per value behind a held slot and returns the order they ran in.
+    async fn run_order(policy: SimulationQueuePolicy, values: &[u64]) -> Vec<u64> {
+        let queue = get_queue(policy, 5_000);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let permit = queue.acquire(U256::ZERO).await.unwrap();
+
+        let mut handles = Vec::new();
+        for value in values.iter().copied() {
+            let queue = queue.clone();
+            let order = order.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = queue.acquire(U256::from(value)).await.unwrap();
+                order.lock().unwrap().push(value);
+            }));
+            // Make sure each simulation is queued before the next arrives
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        drop(permit);
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        Arc::try_unwrap(order).unwrap().into_inner().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_highest_value_simulated_first() {
+        let order = run_order(SimulationQueuePolicy::HighestValue, &[1, 5, 3, 5]).await;
+        assert_eq!(order, vec![5, 5, 3, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_fifo_keeps_arrival_order() {
+        let order = run_order(SimulationQueuePolicy::Fifo, &[1, 5, 3]).await;
+        assert_eq!(order, vec![1, 5, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_waiting_past_max_wait_is_dropped() {
+        let queue = get_queue(SimulationQueuePolicy::HighestValue, 50);
+        let permit = queue.acquire(U256::ZERO).await.unwrap();
+
+        let result = queue.acquire(U256::from(10)).await;
+        assert!(matches!(result, Err(BlockSimError::SimulationQueueTimeout)));
+
+        // The dropped simulation does not hold on to the slot
+        drop(permit);
+        assert!(queue.acquire(U256::ZERO).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_waiting_is_not_dropped() {
+        let queue = get_queue(SimulationQueuePolicy::HighestValue, 50);
+        let permit = queue.acquire(U256::ZERO).await.unwrap();
+
+        let waiting_queue = queue.clone();
+        let handle =
+            tokio::spawn(async move { waiting_queue.acquire_unbounded(U256::from(10)).await });
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(!handle.is_finished());
+
+        drop(permit);
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_waiter_gives_back_handed_over_slot() {
+        let queue = get_queue(SimulationQueuePolicy::HighestValue, 50);
+        let permit = queue.acquire(U256::ZERO).await.unwrap();
+
+        let mut waiting = Box::pin(queue.acquire_unbounded(U256::from(10)));
+        assert!(futures::poll!(&mut waiting).is_pending());
+
+        // The slot is handed over to the waiter, which is cancelled before it runs
+        drop(permit);
+        drop(waiting);
+
+        assert!(queue.acquire(U256::ZERO).await.is_ok());
+    }
+}
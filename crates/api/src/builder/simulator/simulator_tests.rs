@@ -23,7 +23,7 @@ mod simulator_tests {
     // ++++ HELPERS ++++
     fn get_simulator(endpoint: &str) -> RpcSimulator {
         let http = Client::new();
-        RpcSimulator::new(http, endpoint.to_string())
+        RpcSimulator::new(http, endpoint.to_string(), None)
     }
 
     fn get_byte_vector_32_for_hex(hex: &str) -> ByteVector<32> {
@@ -71,7 +71,7 @@ mod simulator_tests {
         assert!(result.is_ok());
         let received_sim_res = sim_res_receiver.recv().await.unwrap();
         match received_sim_res {
-            DbInfo::SimulationResult { block_hash, block_sim_result } => {
+            DbInfo::SimulationResult { block_hash, block_sim_result, .. } => {
                 assert_eq!(
                     block_hash,
                     get_byte_vector_32_for_hex(
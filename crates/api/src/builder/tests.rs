@@ -4,12 +4,18 @@ mod tests {
     // +++ IMPORTS +++
     use crate::{
         builder::{
-            api::{decode_header_submission, decode_payload, BuilderApi, MAX_PAYLOAD_LENGTH},
+            access_policy::BuilderAccessPolicy,
+            api::{
+                calculate_expected_gas_limit, decode_header_submission, decode_payload,
+                sanity_check_block_submission, BuilderApi, MAX_PAYLOAD_LENGTH,
+            },
+            error::BuilderApiError,
             mock_simulator::MockSimulator,
         },
         gossiper::mock_gossiper::MockGossiper,
+        middleware::request_id::request_id::REQUEST_ID_HEADER,
         service::API_REQUEST_TIMEOUT,
-        test_utils::builder_api_app,
+        test_utils::builder_api_app_with_auctioneer,
     };
     use axum::{ http::{header, Method, Request, Uri}};
     use tokio_tungstenite::{connect_async, tungstenite::{self, Message}};
@@ -21,14 +27,18 @@ mod tests {
     use helix_beacon_client::types::PayloadAttributes;
     use helix_common::{
         api::{
-            builder_api::{BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry, TopBidUpdate},
+            builder_api::{
+                BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry, MinBidValueUpdate,
+                TopBidUpdate,
+            },
             proposer_api::ValidatorRegistrationInfo,
         }, bid_submission::{
             v2::header_submission::{
                 SignedHeaderSubmission, SignedHeaderSubmissionCapella, SignedHeaderSubmissionDeneb,
             },
             BidSubmission, SignedBidSubmission,
-        }, HeaderSubmissionTrace, Route, SubmissionTrace, ValidatorPreferences
+        }, chain_info::ChainInfo, BuilderAccessConfig, BuilderAccessMode, HeaderSubmissionTrace,
+        RecordedTrace, Route, SubmissionTrace, TraceOutcome, TraceRecordResponse, ValidatorPreferences
     };
     use helix_database::MockDatabaseService;
     use helix_datastore::MockAuctioneer;
@@ -47,6 +57,7 @@ mod tests {
         oneshot,
     };
     use tonic::transport::Body;
+    use uuid::Uuid;
 
     // +++ HELPER VARIABLES +++
     const ADDRESS: &str = "0.0.0.0";
@@ -138,6 +149,15 @@ mod tests {
         }
     }
 
+    fn get_next_duty_for_submission(
+        signed_bid_submission: &SignedBidSubmission,
+    ) -> BuilderGetValidatorsResponseEntry {
+        let mut next_duty = get_valid_payload_register_validator(None);
+        next_duty.entry.registration.message.public_key =
+            signed_bid_submission.proposer_public_key().clone();
+        next_duty
+    }
+
     fn get_dummy_slot_update(head_slot: Option<u64>, submission_slot: Option<u64>) -> SlotUpdate {
         SlotUpdate {
             slot: head_slot.unwrap_or(HEAD_SLOT),
@@ -168,6 +188,7 @@ mod tests {
             ),
             withdrawals_root: None,
             payload_attributes: get_dummy_payload_attributes(),
+            parent_gas_limit: Some(30_000_000),
         }
     }
 
@@ -284,12 +305,23 @@ mod tests {
         HttpServiceConfig,
         Arc<BuilderApi<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>>,
         Receiver<Sender<ChainUpdate>>,
+    ) {
+        start_api_server_with_auctioneer(MockAuctioneer::default()).await
+    }
+
+    async fn start_api_server_with_auctioneer(
+        auctioneer: MockAuctioneer,
+    ) -> (
+        oneshot::Sender<()>,
+        HttpServiceConfig,
+        Arc<BuilderApi<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>>,
+        Receiver<Sender<ChainUpdate>>,
     ) {
         let (tx, rx) = oneshot::channel();
         let http_config = HttpServiceConfig::new(ADDRESS, PORT);
         let bind_address = http_config.bind_address();
 
-        let (router, api, slot_update_receiver) = builder_api_app();
+        let (router, api, slot_update_receiver) = builder_api_app_with_auctioneer(auctioneer);
 
         // Run the app in a background task
         tokio::spawn(async move {
@@ -400,9 +432,10 @@ mod tests {
 
         let mut header_submission_trace = HeaderSubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &access_policy, &mut header_submission_trace, &uuid).await.unwrap();
 
         assert_eq!(decoded_submission.0.slot(), 5552306);
         assert!(matches!(
@@ -440,9 +473,10 @@ mod tests {
 
         let mut header_submission_trace = HeaderSubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, false, true, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &access_policy, &mut header_submission_trace, &uuid).await.unwrap();
 
         assert!(matches!(decoded_submission.0, SignedHeaderSubmission::Capella(_)));
         assert!(decoded_submission.0.commitments().is_none());
@@ -464,9 +498,10 @@ mod tests {
 
         let mut header_submission_trace = HeaderSubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, false, true, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &access_policy, &mut header_submission_trace, &uuid).await.unwrap();
 
         assert!(matches!(decoded_submission.0, SignedHeaderSubmission::Deneb(_)));
         assert!(decoded_submission.0.commitments().is_some());
@@ -488,9 +523,10 @@ mod tests {
 
         let mut submission_trace = SubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let decoded_submission =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &access_policy, &mut submission_trace, &uuid).await.unwrap();
 
         assert_eq!(decoded_submission.0.message().slot, 5552306);
         assert!(matches!(decoded_submission.0.execution_payload(), ExecutionPayload::Capella(_)));
@@ -510,9 +546,10 @@ mod tests {
 
         let mut submission_trace = SubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, true, false, &req_payload_bytes);
         let decoded_submission =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &access_policy, &mut submission_trace, &uuid).await.unwrap();
 
         assert_eq!(decoded_submission.0.message().slot, 5552306);
         assert!(matches!(decoded_submission.0.execution_payload(), ExecutionPayload::Capella(_)));
@@ -532,9 +569,10 @@ mod tests {
 
         let mut submission_trace = SubmissionTrace::default();
         let uuid = uuid::Uuid::new_v4();
+        let access_policy = BuilderAccessPolicy::default();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let (decoded_submission, _) =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &access_policy, &mut submission_trace, &uuid).await.unwrap();
 
         assert_eq!(decoded_submission.message().slot, 5552306);
         assert!(matches!(decoded_submission.execution_payload(), ExecutionPayload::Deneb(_)));
@@ -704,6 +742,431 @@ mod tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_submit_block_incorrect_gas_limit() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver) = start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        // Prepare the request
+        let req_url = format!("{}{}", http_config.base_url(), Route::SubmitBlock.path());
+
+        let mut signed_bid_submission: SignedBidSubmission = load_bid_submission();
+
+        // The parent and registered gas limits are both 30M, so the gas limit must not move
+        signed_bid_submission.message_mut().gas_limit = 36_000_000;
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            "Incorrect gas limit. got: 36000000, expected: 30000000"
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    async fn update_builder_access(http_config: &HttpServiceConfig, config: &BuilderAccessConfig) {
+        let req_url = format!("{}{}", http_config.base_url(), Route::UpdateBuilderAccess.path());
+        let resp = reqwest::Client::new().post(req_url.as_str()).json(config).send().await.unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_submit_block_builder_access_hot_reload() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver) = start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::SubmitBlock.path());
+        let signed_bid_submission: SignedBidSubmission = load_bid_submission();
+        let builder_pub_key = signed_bid_submission.builder_public_key().clone();
+
+        // Deny the builder
+        let denylist = BuilderAccessConfig {
+            mode: BuilderAccessMode::Denylist,
+            builders: [builder_pub_key.clone()].into_iter().collect(),
+        };
+        update_builder_access(&http_config, &denylist).await;
+
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            format!("Builder {builder_pub_key:?} is not allowed to submit to this relay")
+        );
+
+        // Builders missing from an allowlist are denied too
+        let allowlist = BuilderAccessConfig {
+            mode: BuilderAccessMode::Allowlist,
+            builders: [BlsPublicKey::default()].into_iter().collect(),
+        };
+        update_builder_access(&http_config, &allowlist).await;
+
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+
+        // Reopening the relay lets the builder through on the next submission
+        update_builder_access(&http_config, &BuilderAccessConfig::default()).await;
+
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_ne!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    async fn update_min_bid_value(http_config: &HttpServiceConfig, min_bid_value: U256) {
+        let req_url = format!("{}{}", http_config.base_url(), Route::UpdateMinBidValue.path());
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .json(&MinBidValueUpdate { min_bid_value })
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_submit_block_min_bid_value() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver) = start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::SubmitBlock.path());
+        let signed_bid_submission: SignedBidSubmission = load_bid_submission();
+        let value = signed_bid_submission.value();
+
+        // Bids below the minimum are rejected
+        let min_bid_value = value + U256::from(1);
+        update_min_bid_value(&http_config, min_bid_value).await;
+
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            format!("Bid value below minimum. got: {value}, min: {min_bid_value}")
+        );
+
+        // Bids at the minimum proceed to the remaining checks
+        update_min_bid_value(&http_config, value).await;
+
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert!(!resp.text().await.unwrap().starts_with("Bid value below minimum"));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_submit_block_below_min_bid_value_with_cancellations() {
+        // Start the server
+        let auctioneer = MockAuctioneer::default();
+        let (tx, http_config, _api, mut slot_update_receiver) =
+            start_api_server_with_auctioneer(auctioneer.clone()).await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        let req_url =
+            format!("{}{}?cancellations=1", http_config.base_url(), Route::SubmitBlock.path());
+        let signed_bid_submission: SignedBidSubmission = load_bid_submission();
+        let value = signed_bid_submission.value();
+        update_min_bid_value(&http_config, value + U256::from(1)).await;
+
+        // A cancellable bid below the minimum is rejected and takes the previous bid down with it
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert!(resp.text().await.unwrap().starts_with("Bid value below minimum"));
+        assert_eq!(
+            *auctioneer.deleted_builder_bids.lock().unwrap(),
+            vec![signed_bid_submission.builder_public_key().clone()]
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recorded_trace_is_retrievable() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver) = start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        // The dummy submission is rejected once its signature is checked
+        let mut signed_bid_submission: SignedBidSubmission = load_bid_submission();
+        signed_bid_submission.message_mut().proposer_public_key =
+            get_valid_payload_register_validator(None).entry.registration.message.public_key;
+
+        let request_id = Uuid::new_v4();
+        let req_url = format!("{}{}", http_config.base_url(), Route::SubmitBlock.path());
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("Content-Type", "application/json")
+            .header(REQUEST_ID_HEADER, request_id.to_string())
+            .json(&signed_bid_submission)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+
+        let req_url = format!("{}{}?limit=10", http_config.base_url(), Route::RecordedTraces.path());
+        let resp = reqwest::Client::new().get(req_url.as_str()).send().await.unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+        let records: Vec<TraceRecordResponse> = resp.json().await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].record.request_id, request_id.to_string());
+        assert_eq!(records[0].record.slot, SUBMISSION_SLOT);
+        assert_eq!(records[0].record.outcome, TraceOutcome::Rejected);
+        assert_eq!(records[0].record.error.as_deref(), Some("Signature verification failed"));
+        let checkpoints: Vec<_> =
+            records[0].segments.iter().map(|segment| segment.to.as_str()).collect();
+        assert_eq!(checkpoints.first(), Some(&"decode"));
+        assert_eq!(checkpoints.last(), Some(&"request_finish"));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_submit_block_records_ms_into_slot() {
+        // Start the server
+        let (tx, http_config, api, mut slot_update_receiver) = start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None).await;
+        send_dummy_payload_attributes_update(slot_update_sender, None).await;
+
+        // Received 250ms before the submission slot starts
+        let chain_info = ChainInfo::for_mainnet();
+        let slot_start_ns = (chain_info.genesis_time_in_secs +
+            SUBMISSION_SLOT * chain_info.seconds_per_slot) *
+            1_000_000_000;
+        let req = generate_request(
+            false,
+            false,
+            false,
+            &serde_json::to_vec(&load_bid_submission()).unwrap(),
+        );
+        let _ = BuilderApi::submit_block_received_at(
+            api,
+            Uuid::new_v4(),
+            req,
+            slot_start_ns - 250_000_000,
+        )
+        .await;
+
+        let req_url = format!("{}{}?limit=10", http_config.base_url(), Route::RecordedTraces.path());
+        let resp = reqwest::Client::new().get(req_url.as_str()).send().await.unwrap();
+        let records: Vec<TraceRecordResponse> = resp.json().await.unwrap();
+        assert_eq!(records.len(), 1);
+        match &records[0].record.trace {
+            RecordedTrace::Submission(trace) => assert_eq!(trace.ms_into_slot, -250),
+            trace => panic!("unexpected trace: {trace:?}"),
+        }
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[test]
+    fn test_sanity_check_block_submission_matching_registration() {
+        let signed_bid_submission = load_bid_submission();
+
+        let result = sanity_check_block_submission(
+            &signed_bid_submission,
+            signed_bid_submission.bid_trace(),
+            &get_next_duty_for_submission(&signed_bid_submission),
+            &get_dummy_payload_attributes_update(None),
+            &ChainInfo::for_mainnet(),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_sanity_check_block_submission_fee_recipient_mismatch() {
+        let mut signed_bid_submission = load_bid_submission();
+        signed_bid_submission.message_mut().proposer_fee_recipient =
+            get_byte_vector_20_for_hex("0x1230dde14e7256340cc820415a6022a7d1c93a35");
+
+        let result = sanity_check_block_submission(
+            &signed_bid_submission,
+            signed_bid_submission.bid_trace(),
+            &get_next_duty_for_submission(&signed_bid_submission),
+            &get_dummy_payload_attributes_update(None),
+            &ChainInfo::for_mainnet(),
+        );
+
+        assert!(matches!(result, Err(BuilderApiError::FeeRecipientMismatch { .. })));
+    }
+
+    #[test]
+    fn test_calculate_expected_gas_limit() {
+        // Moves towards the target by less than parent / 1024
+        assert_eq!(calculate_expected_gas_limit(30_000_000, 36_000_000), 30_029_295);
+        assert_eq!(calculate_expected_gas_limit(36_000_000, 30_000_000), 35_964_845);
+        // Stops at the target once within a single step
+        assert_eq!(calculate_expected_gas_limit(30_000_000, 30_010_000), 30_010_000);
+        assert_eq!(calculate_expected_gas_limit(30_000_000, 29_990_000), 29_990_000);
+        assert_eq!(calculate_expected_gas_limit(30_000_000, 30_000_000), 30_000_000);
+    }
+
+    #[test]
+    fn test_sanity_check_block_submission_gas_limit() {
+        // The validator registered 36M while the parent block is at 30M
+        let mut next_duty = get_next_duty_for_submission(&load_bid_submission());
+        next_duty.entry.registration.message.gas_limit = 36_000_000;
+        let payload_attributes = get_dummy_payload_attributes_update(None);
+        let chain_info = ChainInfo::for_mainnet();
+
+        let check_gas_limit = |gas_limit: u64, payload_attributes: &PayloadAttributesUpdate| {
+            let mut signed_bid_submission = load_bid_submission();
+            signed_bid_submission.message_mut().gas_limit = gas_limit;
+            sanity_check_block_submission(
+                &signed_bid_submission,
+                signed_bid_submission.bid_trace(),
+                &next_duty,
+                payload_attributes,
+                &chain_info,
+            )
+        };
+
+        // A single step from the parent towards the registered gas limit
+        assert!(check_gas_limit(30_029_295, &payload_attributes).is_ok());
+
+        // Staying at the parent gas limit, stepping too far or jumping to the registered one
+        for gas_limit in [30_000_000, 30_029_296, 36_000_000] {
+            assert!(matches!(
+                check_gas_limit(gas_limit, &payload_attributes),
+                Err(BuilderApiError::IncorrectGasLimit { got, expected: 30_029_295 })
+                    if got == gas_limit
+            ));
+        }
+
+        // Without the parent gas limit the check is skipped
+        let payload_attributes =
+            PayloadAttributesUpdate { parent_gas_limit: None, ..payload_attributes };
+        assert!(check_gas_limit(36_000_000, &payload_attributes).is_ok());
+    }
+
+    #[test]
+    fn test_sanity_check_block_submission_gas_used_exceeds_gas_limit() {
+        let mut signed_bid_submission = load_bid_submission();
+        let gas_limit = signed_bid_submission.gas_limit();
+        signed_bid_submission.message_mut().gas_used = gas_limit + 1;
+
+        let result = sanity_check_block_submission(
+            &signed_bid_submission,
+            signed_bid_submission.bid_trace(),
+            &get_next_duty_for_submission(&signed_bid_submission),
+            &get_dummy_payload_attributes_update(None),
+            &ChainInfo::for_mainnet(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(BuilderApiError::GasUsedExceedsGasLimit { gas_used, gas_limit: limit })
+                if gas_used == gas_limit + 1 && limit == gas_limit
+        ));
+    }
+
+    #[test]
+    fn test_sanity_check_block_submission_ok() {
+        // A block using all of its gas passes every static check and proceeds to simulation
+        let mut signed_bid_submission = load_bid_submission();
+        let gas_limit = signed_bid_submission.gas_limit();
+        signed_bid_submission.message_mut().gas_used = gas_limit;
+
+        let result = sanity_check_block_submission(
+            &signed_bid_submission,
+            signed_bid_submission.bid_trace(),
+            &get_next_duty_for_submission(&signed_bid_submission),
+            &get_dummy_payload_attributes_update(None),
+            &ChainInfo::for_mainnet(),
+        );
+
+        assert!(result.is_ok(), "unexpected error: {:?}", result.err());
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_submit_block_submission_for_past_slot() {
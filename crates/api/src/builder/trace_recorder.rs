@@ -0,0 +1,72 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use helix_common::{TraceRecord, TraceRecorderConfig};
+
+/// Keeps the most recent completed submission traces in a bounded ring buffer so operators can
+/// inspect slow slots through the admin API.
+///
+/// A disabled recorder drops every trace.
+#[derive(Default)]
+pub struct TraceRecorder {
+    capacity: usize,
+    records: Mutex<VecDeque<TraceRecord>>,
+}
+
+impl TraceRecorder {
+    pub fn new(config: &TraceRecorderConfig) -> Self {
+        let capacity = if config.enabled { config.capacity } else { 0 };
+        Self { capacity, records: Mutex::new(VecDeque::with_capacity(capacity)) }
+    }
+
+    pub fn record(&self, record: TraceRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap();
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Returns up to `limit` records, most recent first.
+    pub fn latest(&self, limit: usize) -> Vec<TraceRecord> {
+        self.records.lock().unwrap().iter().rev().take(limit).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use helix_common::{RecordedTrace, SubmissionTrace, TraceOutcome};
+
+    use super::*;
+
+    fn get_record(slot: u64) -> TraceRecord {
+        TraceRecord {
+            request_id: slot.to_string(),
+            slot,
+            outcome: TraceOutcome::BidSaved,
+            error: None,
+            trace: RecordedTrace::Submission(SubmissionTrace::default()),
+        }
+    }
+
+    #[test]
+    fn test_keeps_most_recent_records_up_to_capacity() {
+        let recorder = TraceRecorder::new(&TraceRecorderConfig { enabled: true, capacity: 3 });
+        for slot in 0..5 {
+            recorder.record(get_record(slot));
+        }
+
+        let slots: Vec<_> = recorder.latest(10).iter().map(|record| record.slot).collect();
+        assert_eq!(slots, vec![4, 3, 2]);
+        assert_eq!(recorder.latest(1).len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_recorder_drops_records() {
+        let recorder = TraceRecorder::new(&TraceRecorderConfig { enabled: false, capacity: 3 });
+        recorder.record(get_record(1));
+        assert!(recorder.latest(10).is_empty());
+    }
+}
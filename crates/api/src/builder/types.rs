@@ -1,6 +1,7 @@
 use std::{sync::Arc};
 
 
+use ethereum_consensus::primitives::BlsPublicKey;
 use helix_common::{
     bellatrix::ByteVector, bid_submission::{v2::header_submission::SignedHeaderSubmission, SignedBidSubmission}, simulator::BlockSimError, GossipedHeaderTrace, GossipedPayloadTrace, HeaderSubmissionTrace, SignedBuilderBid, SubmissionTrace
 };
@@ -20,6 +21,11 @@ pub enum DbInfo {
     SimulationResult {
         block_hash: ByteVector<32>,
         block_sim_result: Result<(), BlockSimError>,
+        builder_pub_key: BlsPublicKey,
+        latency_ms: u64,
+        /// Optimistic simulations run after the submission returned, so their outcome only
+        /// reaches the builder's reputation through here.
+        is_optimistic: bool,
     },
 }
 
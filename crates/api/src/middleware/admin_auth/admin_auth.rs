@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::header::AUTHORIZATION,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use helix_common::{api::is_admin_path, AdminAuthConfig, AdminToken};
+use tracing::{debug, warn};
+
+use super::error::AdminAuthRequired;
+
+/// Tokens accepted on the admin API routes.
+#[derive(Clone)]
+pub struct AdminAuthState {
+    tokens: Arc<Vec<AdminToken>>,
+}
+
+impl AdminAuthState {
+    pub fn new(config: &AdminAuthConfig) -> Self {
+        AdminAuthState { tokens: Arc::new(config.tokens.clone()) }
+    }
+
+    /// Returns the label of the configured token matching `token`, if any.
+    fn label_for(&self, token: &str) -> Option<&str> {
+        self.tokens
+            .iter()
+            .find(|admin_token| tokens_match(&admin_token.token, token))
+            .map(|admin_token| admin_token.label.as_str())
+    }
+}
+
+/// Rejects admin API requests without a valid `Authorization: Bearer <token>` header with a `401`.
+///
+/// Applied to the whole router and matched on the request path, so every admin route is covered
+/// regardless of how it is registered. Other requests pass through untouched.
+pub async fn require_admin_auth(
+    State(state): State<AdminAuthState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !is_admin_path(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.strip_prefix("Bearer "));
+    match token.and_then(|token| state.label_for(token)) {
+        Some(label) => {
+            debug!(route = request.uri().path(), token = label, "admin request authenticated");
+            next.run(request).await
+        }
+        None => {
+            warn!(route = request.uri().path(), "rejecting unauthenticated admin request");
+            AdminAuthRequired::new().into_response()
+        }
+    }
+}
+
+/// Compares in constant time for tokens of the same length.
+fn tokens_match(expected: &str, provided: &str) -> bool {
+    let (expected, provided) = (expected.as_bytes(), provided.as_bytes());
+    expected.len() == provided.len() &&
+        expected.iter().zip(provided).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
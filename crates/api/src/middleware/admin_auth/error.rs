@@ -0,0 +1,32 @@
+//! Error types
+
+use std::{error, fmt};
+
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+/// The admin request carries no valid bearer token.
+#[derive(Debug, Default)]
+pub struct AdminAuthRequired(pub(super) ());
+
+impl AdminAuthRequired {
+    pub fn new() -> Self {
+        AdminAuthRequired(())
+    }
+}
+
+impl fmt::Display for AdminAuthRequired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("missing or invalid admin token")
+    }
+}
+
+impl error::Error for AdminAuthRequired {}
+
+impl IntoResponse for AdminAuthRequired {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, "Bearer")]).into_response()
+    }
+}
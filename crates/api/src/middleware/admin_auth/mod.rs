@@ -0,0 +1,3 @@
+pub mod admin_auth;
+pub mod error;
+pub mod tests;
@@ -0,0 +1,95 @@
+#![cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{middleware, routing::get, Router};
+    use helix_common::{AdminAuthConfig, AdminToken, Route};
+    use reqwest::StatusCode;
+    use serial_test::serial;
+    use tokio::sync::oneshot;
+
+    use crate::middleware::admin_auth::admin_auth::{require_admin_auth, AdminAuthState};
+
+    async fn start_server(config: AdminAuthConfig) -> oneshot::Sender<()> {
+        let (tx, rx) = oneshot::channel();
+
+        let router = Router::new()
+            .route(&Route::UpdateMinBidValue.path(), get(|| async { "admin" }))
+            .route(&Route::BidsReceived.path(), get(|| async { "data" }))
+            .layer(middleware::from_fn_with_state(AdminAuthState::new(&config), require_admin_auth));
+
+        tokio::spawn(async move {
+            let listener = tokio::net::TcpListener::bind("0.0.0.0:4043").await.unwrap();
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    rx.await.ok();
+                })
+                .await
+                .unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        tx
+    }
+
+    fn get_config() -> AdminAuthConfig {
+        AdminAuthConfig {
+            tokens: vec![
+                AdminToken { label: "old".to_string(), token: "old-token".to_string() },
+                AdminToken { label: "new".to_string(), token: "new-token".to_string() },
+            ],
+        }
+    }
+
+    async fn send_request(route: Route, authorization: Option<&str>) -> StatusCode {
+        let mut request =
+            reqwest::Client::new().get(format!("http://localhost:4043{}", route.path()));
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        request.send().await.unwrap().status()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_admin_request_with_any_configured_token_is_accepted() {
+        let tx = start_server(get_config()).await;
+
+        for authorization in ["Bearer old-token", "Bearer new-token"] {
+            let status = send_request(Route::UpdateMinBidValue, Some(authorization)).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_admin_request_without_valid_token_is_rejected() {
+        let tx = start_server(get_config()).await;
+
+        for authorization in [None, Some("Bearer wrong-token"), Some("Bearer old"), Some("old-token")] {
+            let status = send_request(Route::UpdateMinBidValue, authorization).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+
+        // Other routes do not require a token
+        assert_eq!(send_request(Route::BidsReceived, None).await, StatusCode::OK);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_admin_request_is_rejected_without_configured_tokens() {
+        let tx = start_server(AdminAuthConfig::default()).await;
+
+        let status = send_request(Route::UpdateMinBidValue, Some("Bearer ")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+}
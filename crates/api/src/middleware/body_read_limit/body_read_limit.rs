@@ -0,0 +1,86 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::Semaphore;
+use tracing::{debug, warn};
+
+use super::error::BodyReadLimitExceeded;
+
+/// Caps the number of request bodies buffered in memory at the same time.
+///
+/// Requests arriving while all permits are taken are shed with a `503` instead of being
+/// buffered, so a flood of max size submissions cannot exhaust memory. A permit is held until
+/// the response is produced, as the buffered body lives until then.
+#[derive(Clone)]
+pub struct BodyReadLimitState {
+    permits: Arc<Semaphore>,
+    max_body_size: usize,
+    buffered_bytes: Arc<AtomicUsize>,
+}
+
+impl BodyReadLimitState {
+    pub fn new(max_concurrent_reads: usize, max_body_size: usize) -> Self {
+        BodyReadLimitState {
+            permits: Arc::new(Semaphore::new(max_concurrent_reads)),
+            max_body_size,
+            buffered_bytes: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Number of request body bytes currently held in memory.
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes.load(Ordering::Relaxed)
+    }
+}
+
+pub async fn limit_body_reads(
+    State(state): State<BodyReadLimitState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let _permit = match state.permits.clone().try_acquire_owned() {
+        Ok(permit) => permit,
+        Err(_) => {
+            warn!(
+                route = request.uri().path(),
+                buffered_bytes = state.buffered_bytes(),
+                "shedding request, too many in-flight request bodies"
+            );
+            return BodyReadLimitExceeded::new().into_response();
+        }
+    };
+
+    let (parts, body) = request.into_parts();
+    let body_bytes = match to_bytes(body, state.max_body_size).await {
+        Ok(body_bytes) => body_bytes,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+
+    let size = body_bytes.len();
+    let buffered_bytes = state.buffered_bytes.fetch_add(size, Ordering::Relaxed) + size;
+    let _buffered = BufferedBytesGuard { buffered_bytes: state.buffered_bytes.clone(), size };
+    debug!(size, buffered_bytes, "request body buffered");
+
+    next.run(Request::from_parts(parts, Body::from(body_bytes))).await
+}
+
+/// Releases the buffered bytes even if the request is dropped, e.g. on timeout.
+struct BufferedBytesGuard {
+    buffered_bytes: Arc<AtomicUsize>,
+    size: usize,
+}
+
+impl Drop for BufferedBytesGuard {
+    fn drop(&mut self) {
+        self.buffered_bytes.fetch_sub(self.size, Ordering::Relaxed);
+    }
+}
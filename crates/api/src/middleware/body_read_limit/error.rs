@@ -0,0 +1,29 @@
+//! Error types
+
+use std::{error, fmt};
+
+use axum::{http::StatusCode, response::IntoResponse};
+
+/// Too many request bodies are being read concurrently.
+#[derive(Debug, Default)]
+pub struct BodyReadLimitExceeded(pub(super) ());
+
+impl BodyReadLimitExceeded {
+    pub fn new() -> Self {
+        BodyReadLimitExceeded(())
+    }
+}
+
+impl fmt::Display for BodyReadLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("too many in-flight request bodies")
+    }
+}
+
+impl error::Error for BodyReadLimitExceeded {}
+
+impl IntoResponse for BodyReadLimitExceeded {
+    fn into_response(self) -> axum::response::Response {
+        StatusCode::SERVICE_UNAVAILABLE.into_response()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod body_read_limit;
+pub mod error;
+pub mod tests;
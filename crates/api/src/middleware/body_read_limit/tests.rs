@@ -0,0 +1,107 @@
+#![cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{middleware, routing::post, Router};
+    use reqwest::StatusCode;
+    use serial_test::serial;
+    use tokio::sync::oneshot;
+
+    use crate::middleware::body_read_limit::body_read_limit::{
+        limit_body_reads, BodyReadLimitState,
+    };
+
+    const ROUTE: &str = "/test_body_read_limit";
+    const MAX_BODY_SIZE: usize = 1024 * 1024;
+    const MAX_CONCURRENT_READS: usize = 2;
+
+    async fn start_server(state: BodyReadLimitState) -> oneshot::Sender<()> {
+        let (tx, rx) = oneshot::channel();
+
+        // Holds every request long enough for all concurrent requests to arrive
+        let router = Router::new().route(
+            ROUTE,
+            post(|body: String| async move {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                body.len().to_string()
+            })
+            .layer(middleware::from_fn_with_state(state, limit_body_reads)),
+        );
+
+        tokio::spawn(async move {
+            let listener = tokio::net::TcpListener::bind("0.0.0.0:4041").await.unwrap();
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    rx.await.ok();
+                })
+                .await
+                .unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        tx
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_concurrent_max_size_bodies_are_capped() {
+        let state = BodyReadLimitState::new(MAX_CONCURRENT_READS, MAX_BODY_SIZE);
+        let tx = start_server(state.clone()).await;
+
+        let url = format!("http://localhost:4041{ROUTE}");
+        let client = reqwest::Client::new();
+        let mut requests = Vec::new();
+        for _ in 0..5 {
+            let request = client.post(&url).body("a".repeat(MAX_BODY_SIZE)).send();
+            requests.push(tokio::spawn(request));
+        }
+
+        // Only the admitted requests are buffered
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(state.buffered_bytes(), MAX_CONCURRENT_READS * MAX_BODY_SIZE);
+
+        let mut accepted = 0;
+        let mut shed = 0;
+        for request in requests {
+            let response = request.await.unwrap().unwrap();
+            match response.status() {
+                StatusCode::OK => {
+                    assert_eq!(response.text().await.unwrap(), MAX_BODY_SIZE.to_string());
+                    accepted += 1;
+                }
+                StatusCode::SERVICE_UNAVAILABLE => shed += 1,
+                status => panic!("unexpected status {status}"),
+            }
+        }
+        assert_eq!(accepted, MAX_CONCURRENT_READS);
+        assert_eq!(shed, 5 - MAX_CONCURRENT_READS);
+        assert_eq!(state.buffered_bytes(), 0);
+
+        // Permits are released once the requests complete
+        let response = client.post(&url).body("a").send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_body_above_max_size_is_rejected() {
+        let state = BodyReadLimitState::new(MAX_CONCURRENT_READS, MAX_BODY_SIZE);
+        let tx = start_server(state.clone()).await;
+
+        let url = format!("http://localhost:4041{ROUTE}");
+        let response = reqwest::Client::new()
+            .post(&url)
+            .body("a".repeat(MAX_BODY_SIZE + 1))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(state.buffered_bytes(), 0);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+}
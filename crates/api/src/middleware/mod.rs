@@ -1 +1,5 @@
-pub mod rate_limiting;
\ No newline at end of file
+pub mod admin_auth;
+pub mod body_read_limit;
+pub mod rate_limiting;
+pub mod request_id;
+pub mod route_timeout;
\ No newline at end of file
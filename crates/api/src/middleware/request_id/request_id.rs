@@ -0,0 +1,57 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::{request::Parts, HeaderMap, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Identifies a request in the relay's logs and traces.
+///
+/// Taken from the `X-Request-Id` header so clients can correlate their logs with the relay's.
+/// Only valid UUIDs are accepted, any other value is ignored to keep it out of the logs and a fresh
+/// id is generated instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestId(pub Uuid);
+
+impl RequestId {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let request_id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|val| val.to_str().ok())
+            .and_then(|val| Uuid::parse_str(val).ok())
+            .unwrap_or_else(Uuid::new_v4);
+        Self(request_id)
+    }
+}
+
+/// Uses the id assigned by `assign_request_id`, or reads it from the headers if the route is not
+/// wrapped by the middleware.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for RequestId {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(match parts.extensions.get::<RequestId>() {
+            Some(request_id) => *request_id,
+            None => RequestId::from_headers(&parts.headers),
+        })
+    }
+}
+
+/// Assigns each request its `RequestId` and echoes it in the `X-Request-Id` response header.
+pub async fn assign_request_id(mut request: Request, next: Next) -> Response {
+    let request_id = RequestId::from_headers(request.headers());
+    request.extensions_mut().insert(request_id);
+
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id.0.to_string()) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{middleware, routing::get, Router};
+    use reqwest::StatusCode;
+    use serial_test::serial;
+    use tokio::sync::oneshot;
+    use uuid::Uuid;
+
+    use crate::middleware::request_id::request_id::{
+        assign_request_id, RequestId, REQUEST_ID_HEADER,
+    };
+
+    const ROUTE: &str = "/test_request_id";
+
+    async fn start_server() -> oneshot::Sender<()> {
+        let (tx, rx) = oneshot::channel();
+
+        // Returns the request id seen by the handler
+        let router = Router::new()
+            .route(ROUTE, get(|RequestId(request_id): RequestId| async move { request_id.to_string() }))
+            .layer(middleware::from_fn(assign_request_id));
+
+        tokio::spawn(async move {
+            let listener = tokio::net::TcpListener::bind("0.0.0.0:4042").await.unwrap();
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    rx.await.ok();
+                })
+                .await
+                .unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        tx
+    }
+
+    /// Returns the request id seen by the handler and the one echoed in the response header.
+    async fn send_request(request_id: Option<&str>) -> (String, String) {
+        let mut request = reqwest::Client::new().get(format!("http://localhost:4042{ROUTE}"));
+        if let Some(request_id) = request_id {
+            request = request.header(REQUEST_ID_HEADER, request_id);
+        }
+        let response = request.send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let echoed = response.headers().get(REQUEST_ID_HEADER).unwrap().to_str().unwrap().to_string();
+        (response.text().await.unwrap(), echoed)
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_provided_request_id_is_used_and_echoed() {
+        let tx = start_server().await;
+
+        let request_id = Uuid::new_v4().to_string();
+        let (handler_id, echoed) = send_request(Some(&request_id)).await;
+        assert_eq!(handler_id, request_id);
+        assert_eq!(echoed, request_id);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_missing_or_invalid_request_id_is_replaced() {
+        let tx = start_server().await;
+
+        for request_id in [None, Some("not-a-uuid"), Some("id\" injected=\"value")] {
+            let (handler_id, echoed) = send_request(request_id).await;
+            assert!(Uuid::parse_str(&handler_id).is_ok());
+            assert_eq!(echoed, handler_id);
+            assert_ne!(Some(handler_id.as_str()), request_id);
+        }
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+}
@@ -0,0 +1,29 @@
+//! Error types
+
+use std::{error, fmt};
+
+use axum::{http::StatusCode, response::IntoResponse};
+
+/// The request ran past its route's deadline.
+#[derive(Debug, Default)]
+pub struct RouteTimeoutExceeded(pub(super) ());
+
+impl RouteTimeoutExceeded {
+    pub fn new() -> Self {
+        RouteTimeoutExceeded(())
+    }
+}
+
+impl fmt::Display for RouteTimeoutExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("request exceeded route deadline")
+    }
+}
+
+impl error::Error for RouteTimeoutExceeded {}
+
+impl IntoResponse for RouteTimeoutExceeded {
+    fn into_response(self) -> axum::response::Response {
+        StatusCode::GATEWAY_TIMEOUT.into_response()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod error;
+pub mod route_timeout;
+pub mod tests;
@@ -0,0 +1,50 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::warn;
+
+use super::error::RouteTimeoutExceeded;
+
+/// Deadline for each route with a configured timeout, keyed by route path.
+#[derive(Clone)]
+pub struct RouteTimeoutState {
+    timeout_per_route: Arc<HashMap<String, Duration>>,
+}
+
+impl RouteTimeoutState {
+    pub fn new(timeouts: HashMap<String, Duration>) -> Self {
+        RouteTimeoutState { timeout_per_route: Arc::new(timeouts) }
+    }
+}
+
+/// Aborts requests running past their route's deadline with a `504`.
+///
+/// The handler future is dropped on timeout, so the handler stops at its next await point instead
+/// of running to completion after the client gave up. Tasks it spawned are not cancelled.
+pub async fn enforce_route_timeout(
+    State(state): State<RouteTimeoutState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let timeout = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| state.timeout_per_route.get(path.as_str()))
+        .copied();
+    let Some(timeout) = timeout else {
+        return next.run(request).await;
+    };
+
+    let route = request.uri().path().to_string();
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => {
+            warn!(route, timeout_ms = timeout.as_millis() as u64, "request exceeded route deadline");
+            RouteTimeoutExceeded::new().into_response()
+        }
+    }
+}
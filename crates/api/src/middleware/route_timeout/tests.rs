@@ -0,0 +1,88 @@
+#![cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use axum::{middleware, routing::get, Router};
+    use reqwest::StatusCode;
+    use serial_test::serial;
+    use tokio::sync::oneshot;
+
+    use crate::middleware::route_timeout::route_timeout::{enforce_route_timeout, RouteTimeoutState};
+
+    const SLOW_ROUTE: &str = "/test_route_timeout/slow";
+    const UNLIMITED_ROUTE: &str = "/test_route_timeout/unlimited";
+
+    async fn start_server(finished: Arc<AtomicBool>) -> oneshot::Sender<()> {
+        let (tx, rx) = oneshot::channel();
+
+        // Both routes take 300ms, only the slow route has a deadline
+        let handler = move || {
+            let finished = finished.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                finished.store(true, Ordering::Relaxed);
+            }
+        };
+        let timeouts = HashMap::from([(SLOW_ROUTE.to_string(), Duration::from_millis(100))]);
+        let router = Router::new()
+            .route(SLOW_ROUTE, get(handler.clone()))
+            .route(UNLIMITED_ROUTE, get(handler))
+            .route_layer(middleware::from_fn_with_state(
+                RouteTimeoutState::new(timeouts),
+                enforce_route_timeout,
+            ));
+
+        tokio::spawn(async move {
+            let listener = tokio::net::TcpListener::bind("0.0.0.0:4044").await.unwrap();
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    rx.await.ok();
+                })
+                .await
+                .unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        tx
+    }
+
+    async fn send_request(route: &str) -> StatusCode {
+        reqwest::get(format!("http://localhost:4044{route}")).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_request_past_route_deadline_is_aborted() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let tx = start_server(finished.clone()).await;
+
+        assert_eq!(send_request(SLOW_ROUTE).await, StatusCode::GATEWAY_TIMEOUT);
+
+        // The handler is stopped rather than left running
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert!(!finished.load(Ordering::Relaxed));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_route_without_deadline_runs_to_completion() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let tx = start_server(finished.clone()).await;
+
+        assert_eq!(send_request(UNLIMITED_ROUTE).await, StatusCode::OK);
+        assert!(finished.load(Ordering::Relaxed));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+}
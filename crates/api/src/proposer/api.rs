@@ -1,4 +1,5 @@
 use std::{
+    convert::Infallible,
     sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -6,21 +7,25 @@ use std::{
 use axum::{
     body::{to_bytes, Body},
     extract::{Json, Path},
-    http::{HeaderMap, Request, StatusCode},
-    response::IntoResponse,
+    http::{header::ACCEPT, HeaderMap, HeaderValue, Request, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     Extension,
 };
 use ethereum_consensus::{
-    builder::SignedValidatorRegistration, clock::get_current_unix_time_in_nanos, deneb::{Context, Root}, phase0::mainnet::SLOTS_PER_EPOCH, primitives::BlsPublicKey, ssz::prelude::*, types::mainnet::{
+    builder::SignedValidatorRegistration, clock::get_current_unix_time_in_nanos, deneb::{Context, Root}, phase0::mainnet::SLOTS_PER_EPOCH, primitives::{BlsPublicKey, Hash32}, ssz::prelude::*, types::mainnet::{
         ExecutionPayloadHeader, ExecutionPayloadHeaderRef, SignedBeaconBlock,
         SignedBlindedBeaconBlock,
     }
 };
 
+use futures::StreamExt;
 use tokio::{
     sync::{
-        mpsc::{self, error::SendError, Receiver, Sender},
-        RwLock,
+        mpsc::{self, error::{SendError, TrySendError}, Receiver, Sender},
+        RwLock, Semaphore,
     },
     time::{sleep, Instant},
 };
@@ -30,22 +35,25 @@ use uuid::Uuid;
 use helix_beacon_client::{types::BroadcastValidation, BlockBroadcaster, MultiBeaconClientTrait};
 use helix_common::{
     api::{
-        builder_api::BuilderGetValidatorsResponseEntry,
+        builder_api::{BuilderGetValidatorsResponseEntry, TopBidUpdate},
         proposer_api::{GetPayloadResponse, ValidatorRegistrationInfo},
     }, chain_info::{ChainInfo, Network}, signed_proposal::VersionedSignedProposal, try_execution_header_from_payload, validator_preferences, versioned_payload::PayloadAndBlobs, BidRequest, Filtering, GetHeaderTrace, GetPayloadTrace, RegisterValidatorsTrace, ValidatorPreferences
 };
 use helix_database::DatabaseService;
 use helix_datastore::{error::AuctioneerError, Auctioneer};
 use helix_housekeeper::{ChainUpdate, SlotUpdate};
-use helix_utils::signing::{verify_signed_builder_message, verify_signed_consensus_message};
+use helix_utils::signing::{verify_signed_consensus_message, verify_validator_registration};
 
-use crate::{builder::api, gossiper::{traits::GossipClientTrait, types::{BroadcastGetPayloadParams, GossipedMessage}}, proposer::{
-    error::ProposerApiError, unblind_beacon_block, GetHeaderParams, PreferencesHeader, GET_HEADER_REQUEST_CUTOFF_MS
+use crate::{builder::api, gossiper::{traits::GossipClientTrait, types::{BroadcastGetPayloadParams, GossipedMessage}}, middleware::request_id::request_id::RequestId, proposer::{
+    error::ProposerApiError, payload_fallback::BuilderPayloadFallback, unblind_beacon_block, GetHeaderParams, PreferencesHeader
 }};
 
 const GET_PAYLOAD_REQUEST_CUTOFF_MS: i64 = 4000;
 pub(crate) const MAX_BLINDED_BLOCK_LENGTH: usize = 1024 * 1024;
+const CONSENSUS_VERSION_HEADER: &str = "eth-consensus-version";
 pub(crate) const MAX_VAL_REGISTRATIONS_LENGTH: usize = 425 * 10_000; // 425 bytes per registration (json) * 10,000 registrations
+pub(crate) const MAX_TOP_BID_SUBSCRIPTIONS: usize = 1_000;
+const TOP_BID_SUBSCRIPTION_BUFFER: usize = 16;
 
 #[derive(Clone)]
 pub struct ProposerApi<A, DB, M, G>
@@ -68,6 +76,16 @@ where
     validator_preferences: Arc<ValidatorPreferences>,
 
     target_get_payload_propagation_duration_ms: u64,
+    /// `get_header` requests later than this into the slot are rejected
+    get_header_request_cutoff_ms: u64,
+    /// Requests up to this late past a cutoff are still accepted, in case the local clock leads
+    clock_skew_tolerance_ms: u64,
+
+    /// Bounds the number of concurrent `subscribe_top_bid` streams
+    top_bid_subscriptions: Arc<Semaphore>,
+
+    /// Fetches payloads missing from the auctioneer from the builder, if configured
+    payload_fallback: Option<Arc<BuilderPayloadFallback>>,
 }
 
 impl<A, DB, M, G> ProposerApi<A, DB, M, G>
@@ -87,8 +105,21 @@ where
         slot_update_subscription: Sender<Sender<ChainUpdate>>,
         validator_preferences: Arc<ValidatorPreferences>,
         target_get_payload_propagation_duration_ms: u64,
+        get_header_request_cutoff_ms: Option<u64>,
+        clock_skew_tolerance_ms: u64,
+        payload_fallback: Option<Arc<BuilderPayloadFallback>>,
         gossip_receiver: Receiver<GossipedMessage>,
     ) -> Self {
+        let get_header_request_cutoff_ms = get_header_request_cutoff_ms
+            .unwrap_or_else(|| chain_info.get_header_request_cutoff_ms());
+        let max_clock_skew_tolerance_ms = chain_info.max_clock_skew_tolerance_ms();
+        if clock_skew_tolerance_ms > max_clock_skew_tolerance_ms {
+            warn!(
+                clock_skew_tolerance_ms,
+                max_clock_skew_tolerance_ms, "clock skew tolerance capped to a twelfth of the slot",
+            );
+        }
+        let clock_skew_tolerance_ms = clock_skew_tolerance_ms.min(max_clock_skew_tolerance_ms);
         let api = Self {
             auctioneer,
             db,
@@ -99,6 +130,10 @@ where
             chain_info,
             validator_preferences,
             target_get_payload_propagation_duration_ms,
+            get_header_request_cutoff_ms,
+            clock_skew_tolerance_ms,
+            top_bid_subscriptions: Arc::new(Semaphore::new(MAX_TOP_BID_SUBSCRIPTIONS)),
+            payload_fallback,
         };
 
         // Spin up gossip processing task
@@ -122,9 +157,14 @@ where
     }
 
     /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/status>
+    ///
+    /// Reports the relay as unavailable while the auctioneer circuit breaker is open.
     pub async fn status(
-        Extension(_proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
     ) -> Result<impl IntoResponse, ProposerApiError> {
+        if !proposer_api.auctioneer.is_available() {
+            return Err(ProposerApiError::AuctioneerUnavailable);
+        }
         Ok(StatusCode::OK)
     }
 
@@ -134,7 +174,7 @@ where
     /// following steps:
     /// 1. Validates the registration timestamp of each validator.
     /// 2. Checks if the validator is known in the validator registry.
-    /// 3. Verifies the signature of each registration.
+    /// 3. Verifies the signature of each registration, in parallel across the batch.
     /// 4. Writes validated registrations to the registry.
     ///
     /// If all registrations in the batch fail validation, an error is returned.
@@ -142,6 +182,7 @@ where
     /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/registerValidator>
     pub async fn register_validators(
         Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        RequestId(request_id): RequestId,
         headers: HeaderMap,
         Json(registrations): Json<Vec<SignedValidatorRegistration>>,
     ) -> Result<StatusCode, ProposerApiError> {
@@ -205,7 +246,6 @@ where
             }
         }
 
-        let request_id = Uuid::new_v4();
         let mut trace =
             RegisterValidatorsTrace { receive: get_nanos_timestamp()?, ..Default::default() };
 
@@ -225,13 +265,9 @@ where
 
         // Check each registration
         let mut valid_registrations = Vec::with_capacity(known_pub_keys.len());
+        let mut registrations_to_verify = Vec::with_capacity(known_pub_keys.len());
 
-        let mut handles = Vec::with_capacity(registrations.len());
-
-        for mut registration in registrations {
-            let proposer_api_clone = proposer_api.clone();
-            let start_time = Instant::now();
-
+        for registration in registrations {
             let pub_key = registration.message.public_key.clone();
 
             debug!(
@@ -251,7 +287,7 @@ where
                 continue;
             }
 
-            if !proposer_api_clone.db.is_registration_update_required(&registration).await? {
+            if !proposer_api.db.is_registration_update_required(&registration).await? {
                 debug!(
                     request_id = %request_id,
                     pub_key = ?pub_key,
@@ -261,36 +297,18 @@ where
                 continue;
             }
 
-            let handle = tokio::task::spawn_blocking(move || {
-                let res = match proposer_api_clone.validate_registration(&mut registration) {
-                    Ok(_) => Some(registration),
-                    Err(err) => {
-                        warn!(
-                            request_id = %request_id,
-                            err = %err,
-                            pub_key = ?pub_key,
-                            "Failed to register validator",
-                        );
-                        None
-                    }
-                };
-
-                trace!(
-                    request_id = %request_id,
-                    pub_key = ?pub_key,
-                    elapsed_time = %start_time.elapsed().as_nanos(),
-                );
-
-                res
-            });
-            handles.push(handle);
+            registrations_to_verify.push(registration);
         }
 
-        for handle in handles {
-            let reg = handle.await.map_err(|_| ProposerApiError::InternalServerError)?;
-            if let Some(reg) = reg {
-                valid_registrations.push(reg);
-            }
+        let (verified_registrations, failed_pub_keys) =
+            proposer_api.verify_registrations(registrations_to_verify, &request_id).await?;
+        valid_registrations.extend(verified_registrations);
+        if !failed_pub_keys.is_empty() {
+            warn!(
+                request_id = %request_id,
+                failed_pub_keys = ?failed_pub_keys,
+                "Failed to verify some registrations",
+            );
         }
         trace.registrations_complete = get_nanos_timestamp()?;
 
@@ -335,14 +353,16 @@ where
     /// 2. Validates the request timestamp to ensure it's not too late.
     /// 3. Fetches the best bid for the given parameters from the auctioneer.
     ///
-    /// The function returns a JSON response containing the best bid if found.
+    /// The function returns the best bid if found, SSZ encoded if the `Accept` header prefers
+    /// `application/octet-stream` and JSON encoded otherwise.
     ///
     /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/getHeader>
     pub async fn get_header(
         Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        RequestId(request_id): RequestId,
+        headers: HeaderMap,
         Path(GetHeaderParams { slot, parent_hash, public_key }): Path<GetHeaderParams>,
     ) -> Result<impl IntoResponse, ProposerApiError> {
-        let request_id = Uuid::new_v4();
         let mut trace = GetHeaderTrace { receive: get_nanos_timestamp()?, ..Default::default() };
 
         let (head_slot, _) = *proposer_api.curr_slot_info.read().await;
@@ -408,7 +428,15 @@ where
                     .await;
 
                 // Return header
-                Ok(axum::Json(bid))
+                let mut response = if accepts_ssz(&headers) {
+                    bid.to_ssz_bytes()?.into_response()
+                } else {
+                    axum::Json(&bid).into_response()
+                };
+                if let Ok(version) = HeaderValue::from_str(&bid.version().to_string()) {
+                    response.headers_mut().insert(CONSENSUS_VERSION_HEADER, version);
+                }
+                Ok(response)
             }
             Ok(None) => {
                 warn!(request_id = %request_id, "no bid found");
@@ -421,6 +449,59 @@ where
         }
     }
 
+    /// Streams the best bid for the specified slot, parent hash, and public key as Server-Sent
+    /// Events.
+    ///
+    /// 1. Validates that the request's slot is not older than the head slot.
+    /// 2. Sends the current best bid, if there is one, immediately.
+    /// 3. Sends the new best bid every time the auctioneer selects a new top bid for the slot.
+    ///
+    /// The stream is closed once the slot has passed, or if the subscriber falls behind.
+    pub async fn subscribe_top_bid(
+        Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        RequestId(request_id): RequestId,
+        Path(GetHeaderParams { slot, parent_hash, public_key }): Path<GetHeaderParams>,
+    ) -> Result<impl IntoResponse, ProposerApiError> {
+
+        let (head_slot, _) = *proposer_api.curr_slot_info.read().await;
+        debug!(
+            request_id = %request_id,
+            event = "subscribe_top_bid",
+            head_slot = head_slot,
+            slot = slot,
+            parent_hash = ?parent_hash,
+            public_key = ?public_key,
+        );
+
+        // Dont allow subscriptions for past slots
+        if slot < head_slot {
+            warn!(request_id = %request_id, "subscription for past slot");
+            return Err(ProposerApiError::RequestForPastSlot { request_slot: slot, head_slot });
+        }
+
+        let permit = match proposer_api.top_bid_subscriptions.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                warn!(request_id = %request_id, "too many top bid subscriptions");
+                return Err(ProposerApiError::TooManyTopBidSubscriptions);
+            }
+        };
+
+        let (tx, rx) = mpsc::channel(TOP_BID_SUBSCRIPTION_BUFFER);
+        let bid_request = BidRequest { slot, parent_hash, public_key };
+        tokio::spawn(async move {
+            // The permit is released once the subscription ends
+            let _permit = permit;
+            proposer_api.stream_top_bids(bid_request, tx, request_id).await;
+        });
+
+        let stream = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (Ok::<_, Infallible>(event), rx))
+        });
+
+        Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+    }
+
     /// Retrieves the execution payload for a given blinded beacon block.
     ///
     /// This function accepts a `SignedBlindedBeaconBlock` as input and performs several steps:
@@ -434,10 +515,10 @@ where
     /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/submitBlindedBlock>
     pub async fn get_payload(
         Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        RequestId(request_id): RequestId,
         req: Request<Body>,
     ) -> Result<impl IntoResponse, ProposerApiError> {
         let mut trace = GetPayloadTrace { receive: get_nanos_timestamp()?, ..Default::default() };
-        let request_id = Uuid::new_v4();
 
         let signed_blinded_block: SignedBlindedBeaconBlock =
             match deserialize_get_payload_bytes(req).await {
@@ -774,6 +855,76 @@ where
     M: MultiBeaconClientTrait + 'static,
     G: GossipClientTrait + 'static,
 {
+    /// Verifies a batch of registrations in parallel.
+    ///
+    /// The batch is split into chunks, each verified on its own blocking task, with at most one
+    /// task per available CPU. Invalid registrations are dropped rather than failing the batch.
+    ///
+    /// Returns the valid registrations and the public keys of the ones that failed verification.
+    pub async fn verify_registrations(
+        &self,
+        registrations: Vec<SignedValidatorRegistration>,
+        request_id: &Uuid,
+    ) -> Result<(Vec<SignedValidatorRegistration>, Vec<BlsPublicKey>), ProposerApiError> {
+        if registrations.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let num_tasks = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let chunk_size = registrations.len().div_ceil(num_tasks);
+
+        let mut handles = Vec::with_capacity(num_tasks);
+        let mut registrations = registrations.into_iter().peekable();
+        while registrations.peek().is_some() {
+            let chunk: Vec<SignedValidatorRegistration> =
+                registrations.by_ref().take(chunk_size).collect();
+            let api = self.clone();
+            let request_id = *request_id;
+
+            handles.push(tokio::task::spawn_blocking(move || {
+                let mut valid = Vec::with_capacity(chunk.len());
+                let mut failed = Vec::new();
+
+                for mut registration in chunk {
+                    let start_time = Instant::now();
+                    let pub_key = registration.message.public_key.clone();
+
+                    match api.validate_registration(&mut registration) {
+                        Ok(_) => valid.push(registration),
+                        Err(err) => {
+                            warn!(
+                                request_id = %request_id,
+                                err = %err,
+                                pub_key = ?pub_key,
+                                "Failed to register validator",
+                            );
+                            failed.push(pub_key.clone());
+                        }
+                    }
+
+                    trace!(
+                        request_id = %request_id,
+                        pub_key = ?pub_key,
+                        elapsed_time = %start_time.elapsed().as_nanos(),
+                    );
+                }
+
+                (valid, failed)
+            }));
+        }
+
+        let mut valid_registrations = Vec::new();
+        let mut failed_pub_keys = Vec::new();
+        for handle in handles {
+            let (valid, failed) =
+                handle.await.map_err(|_| ProposerApiError::InternalServerError)?;
+            valid_registrations.extend(valid);
+            failed_pub_keys.extend(failed);
+        }
+
+        Ok((valid_registrations, failed_pub_keys))
+    }
+
     /// Validate a single registration.
     pub fn validate_registration(
         &self,
@@ -783,14 +934,7 @@ where
         self.validate_registration_time(registration)?;
 
         // Verify the signature
-        let message = &mut registration.message;
-        let public_key = &message.public_key.clone();
-        if let Err(err) = verify_signed_builder_message(
-            message,
-            &registration.signature,
-            public_key,
-            &self.chain_info.context,
-        ) {
+        if let Err(err) = verify_validator_registration(registration, &self.chain_info.context) {
             return Err(ProposerApiError::InvalidSignature(err));
         }
 
@@ -826,25 +970,46 @@ where
 
     /// Validates that the bid request is not sent too late within the current slot.
     ///
-    /// - Only allows requests for the current slot until a certain cutoff time.
-    fn validate_bid_request_time(&self, bid_request: &BidRequest) -> Result<(), ProposerApiError> {
+    /// - Only allows requests for the current slot until `get_header_request_cutoff_ms` into it,
+    ///   plus the clock skew tolerance.
+    pub fn validate_bid_request_time(
+        &self,
+        bid_request: &BidRequest,
+    ) -> Result<(), ProposerApiError> {
         let curr_timestamp_ms = get_millis_timestamp()? as i64;
         let slot_start_timestamp = self.chain_info.genesis_time_in_secs +
             (bid_request.slot * self.chain_info.seconds_per_slot);
         let ms_into_slot = curr_timestamp_ms.saturating_sub((slot_start_timestamp * 1000) as i64);
 
-        if ms_into_slot > GET_HEADER_REQUEST_CUTOFF_MS {
+        if self.is_past_cutoff(ms_into_slot, self.get_header_request_cutoff_ms) {
             warn!(curr_timestamp_ms = curr_timestamp_ms, slot = bid_request.slot, "get_request",);
 
             return Err(ProposerApiError::GetHeaderRequestTooLate {
                 ms_into_slot: ms_into_slot as u64,
-                cutoff: GET_HEADER_REQUEST_CUTOFF_MS as u64,
+                cutoff: self.get_header_request_cutoff_ms,
             });
         }
 
         Ok(())
     }
 
+    /// Returns whether `ms_into_slot` is past `cutoff_ms` by more than the clock skew tolerance.
+    fn is_past_cutoff(&self, ms_into_slot: i64, cutoff_ms: u64) -> bool {
+        if ms_into_slot <= cutoff_ms as i64 {
+            return false;
+        }
+        if ms_into_slot <= (cutoff_ms + self.clock_skew_tolerance_ms) as i64 {
+            info!(
+                ms_into_slot,
+                cutoff_ms,
+                clock_skew_tolerance_ms = self.clock_skew_tolerance_ms,
+                "late request accepted within clock skew tolerance",
+            );
+            return false;
+        }
+        true
+    }
+
     /// Validates the proposal coordinate of a given `SignedBlindedBeaconBlock`.
     ///
     /// - Compares the proposer index of the block with the expected index for the current slot.
@@ -1023,8 +1188,9 @@ where
 
     /// Fetches the execution payload associated with a given slot, public key, and block hash.
     ///
-    /// The function will retry until the slot cutoff is reached.
-    async fn get_execution_payload(
+    /// The function will retry until the slot cutoff is reached. If the payload is missing from the
+    /// auctioneer, it is requested once from the builder when a payload fallback is configured.
+    pub(crate) async fn get_execution_payload(
         &self,
         slot: u64,
         pub_key: &BlsPublicKey,
@@ -1039,11 +1205,28 @@ where
 
         let mut last_error: Option<ProposerApiError> = None;
         let mut first_try = true; // Try at least once to cover case where get_payload is called too late.
+        let mut tried_fallback = false;
         while first_try || get_millis_timestamp()? < slot_cutoff_millis {
             match self.auctioneer.get_execution_payload(slot, pub_key, block_hash).await {
                 Ok(Some(versioned_payload)) => return Ok(versioned_payload),
                 Ok(None) => {
                     warn!(request_id = %request_id, "execution payload not found");
+
+                    if let (Some(payload_fallback), false) = (&self.payload_fallback, tried_fallback) {
+                        tried_fallback = true;
+                        match payload_fallback
+                            .fetch_payload(self.auctioneer.as_ref(), slot, pub_key, block_hash)
+                            .await
+                        {
+                            Ok(versioned_payload) => {
+                                info!(request_id = %request_id, "fetched execution payload from builder");
+                                return Ok(versioned_payload);
+                            }
+                            Err(err) => {
+                                warn!(request_id = %request_id, error = %err, "failed to fetch execution payload from builder");
+                            }
+                        }
+                    }
                 }
                 Err(err) => {
                     error!(request_id = %request_id, error = %err, "error fetching execution payload");
@@ -1074,7 +1257,7 @@ where
         if duration_until_slot_start.as_millis() > 0 {
             info!(request_id = %request_id, "waiting until slot start t=0: {} ms", duration_until_slot_start.as_millis());
             sleep(duration_until_slot_start).await;
-        } else if ms_into_slot > GET_PAYLOAD_REQUEST_CUTOFF_MS {
+        } else if self.is_past_cutoff(ms_into_slot, GET_PAYLOAD_REQUEST_CUTOFF_MS as u64) {
             return Err(ProposerApiError::GetPayloadRequestTooLate {
                 cutoff: GET_PAYLOAD_REQUEST_CUTOFF_MS as u64,
                 request_time: ms_into_slot as u64,
@@ -1141,6 +1324,123 @@ where
         });
     }
 
+    /// Forwards every new top bid for `bid_request` to `tx` until the slot has passed.
+    ///
+    /// Updates are read from the auctioneer's best bid broadcast, so a slow subscriber never
+    /// blocks bid selection. Subscribers whose buffer is full are dropped.
+    async fn stream_top_bids(&self, bid_request: BidRequest, tx: Sender<Event>, request_id: Uuid) {
+        // Subscribe before sending the current best bid so no update is missed in between
+        let mut top_bid_updates = self.auctioneer.get_best_bids().await;
+
+        let slot_end = UNIX_EPOCH +
+            Duration::from_secs(
+                self.chain_info.genesis_time_in_secs +
+                    (bid_request.slot + 1) * self.chain_info.seconds_per_slot,
+            );
+        let slot_end = sleep(slot_end.duration_since(SystemTime::now()).unwrap_or_default());
+        tokio::pin!(slot_end);
+
+        let mut last_sent = None;
+        if !self.send_top_bid(&bid_request, &tx, &mut last_sent, &request_id).await {
+            return;
+        }
+
+        loop {
+            tokio::select! {
+                _ = &mut slot_end => {
+                    debug!(request_id = %request_id, "slot passed, closing top bid subscription");
+                    return;
+                }
+                update = top_bid_updates.next() => {
+                    let update = match update {
+                        Some(Ok(update)) => update,
+                        Some(Err(err)) => {
+                            // Lagging behind the broadcast is fine, the best bid is re-fetched
+                            debug!(request_id = %request_id, err = %err, "missed top bid updates");
+                            if !self.send_top_bid(&bid_request, &tx, &mut last_sent, &request_id).await {
+                                return;
+                            }
+                            continue;
+                        }
+                        None => return,
+                    };
+
+                    let update: TopBidUpdate = match deserialize(&update) {
+                        Ok(update) => update,
+                        Err(err) => {
+                            warn!(request_id = %request_id, err = ?err, "failed to decode top bid update");
+                            continue;
+                        }
+                    };
+
+                    if update.slot != bid_request.slot || update.parent_hash != bid_request.parent_hash {
+                        continue;
+                    }
+
+                    if !self.send_top_bid(&bid_request, &tx, &mut last_sent, &request_id).await {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sends the best bid for `bid_request` to `tx` if it differs from the last bid sent.
+    ///
+    /// Returns `false` if the subscription should be closed.
+    async fn send_top_bid(
+        &self,
+        bid_request: &BidRequest,
+        tx: &Sender<Event>,
+        last_sent: &mut Option<(Hash32, U256)>,
+        request_id: &Uuid,
+    ) -> bool {
+        let bid = match self
+            .auctioneer
+            .get_best_bid(bid_request.slot, &bid_request.parent_hash, &bid_request.public_key)
+            .await
+        {
+            Ok(Some(bid)) => bid,
+            Ok(None) => return true,
+            Err(err) => {
+                error!(request_id = %request_id, error = %err, "error getting bid");
+                return true;
+            }
+        };
+
+        if bid.value() == U256::ZERO {
+            return true;
+        }
+
+        let sent = (bid.block_hash().clone(), bid.value());
+        if last_sent.as_ref() == Some(&sent) {
+            return true;
+        }
+
+        let event = match Event::default().event("top_bid").json_data(&bid) {
+            Ok(event) => event,
+            Err(err) => {
+                error!(request_id = %request_id, err = %err, "failed to encode top bid event");
+                return true;
+            }
+        };
+
+        match tx.try_send(event) {
+            Ok(()) => {
+                *last_sent = Some(sent);
+                true
+            }
+            Err(TrySendError::Full(_)) => {
+                warn!(request_id = %request_id, "top bid subscriber too slow, dropping subscription");
+                false
+            }
+            Err(TrySendError::Closed(_)) => {
+                debug!(request_id = %request_id, "top bid subscriber disconnected");
+                false
+            }
+        }
+    }
+
     async fn is_trusted_proposer(
         &self,
         public_key: &BlsPublicKey,
@@ -1150,12 +1450,94 @@ where
     }
 }
 
-async fn deserialize_get_payload_bytes(
+/// Whether the `Accept` header gives SSZ (`application/octet-stream`) at least the same quality
+/// as JSON. Without an `Accept` header, JSON is returned.
+fn accepts_ssz(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers.get(ACCEPT).and_then(|val| val.to_str().ok()) else {
+        return false;
+    };
+
+    let mut ssz_quality = 0.0;
+    let mut json_quality = 0.0;
+    for media_range in accept.split(',') {
+        let mut params = media_range.split(';').map(str::trim);
+        let media_type = params.next().unwrap_or_default();
+        let quality = params
+            .find_map(|param| param.strip_prefix("q="))
+            .and_then(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        match media_type {
+            "application/octet-stream" => ssz_quality = f32::max(ssz_quality, quality),
+            "application/json" | "*/*" => json_quality = f32::max(json_quality, quality),
+            _ => {}
+        }
+    }
+    ssz_quality > 0.0 && ssz_quality >= json_quality
+}
+
+/// Decodes a `SignedBlindedBeaconBlock` from a JSON or SSZ encoded request body.
+///
+/// SSZ bodies (`Content-Type: application/octet-stream`) are decoded for the fork given in the
+/// `Eth-Consensus-Version` header, falling back to the newest matching fork if it is missing.
+/// Bodies larger than `MAX_BLINDED_BLOCK_LENGTH` are rejected before being read.
+pub async fn deserialize_get_payload_bytes(
     req: Request<Body>,
 ) -> Result<SignedBlindedBeaconBlock, ProposerApiError> {
+    let is_ssz = req
+        .headers()
+        .get("Content-Type")
+        .and_then(|val| val.to_str().ok())
+        .map_or(false, |v| v == "application/octet-stream");
+
+    let consensus_version = req
+        .headers()
+        .get(CONSENSUS_VERSION_HEADER)
+        .and_then(|val| val.to_str().ok())
+        .map(|v| v.to_lowercase());
+
+    let content_length = req
+        .headers()
+        .get("Content-Length")
+        .and_then(|val| val.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if let Some(content_length) = content_length {
+        if content_length > MAX_BLINDED_BLOCK_LENGTH {
+            return Err(ProposerApiError::PayloadTooLarge {
+                max_size: MAX_BLINDED_BLOCK_LENGTH,
+                size: content_length,
+            });
+        }
+    }
+
     let body = req.into_body();
     let body_bytes = to_bytes(body, MAX_BLINDED_BLOCK_LENGTH).await?;
-    Ok(serde_json::from_slice(&body_bytes)?)
+
+    if is_ssz {
+        decode_ssz_signed_blinded_block(&body_bytes, consensus_version.as_deref())
+    } else {
+        Ok(serde_json::from_slice(&body_bytes)?)
+    }
+}
+
+fn decode_ssz_signed_blinded_block(
+    bytes: &[u8],
+    consensus_version: Option<&str>,
+) -> Result<SignedBlindedBeaconBlock, ProposerApiError> {
+    match consensus_version {
+        Some("deneb") => Ok(SignedBlindedBeaconBlock::Deneb(deserialize(bytes)?)),
+        Some("capella") => Ok(SignedBlindedBeaconBlock::Capella(deserialize(bytes)?)),
+        Some("bellatrix") => Ok(SignedBlindedBeaconBlock::Bellatrix(deserialize(bytes)?)),
+        Some(_) => Err(ProposerApiError::InvalidFork),
+        None => {
+            if let Ok(block) = deserialize(bytes) {
+                return Ok(SignedBlindedBeaconBlock::Deneb(block));
+            }
+            if let Ok(block) = deserialize(bytes) {
+                return Ok(SignedBlindedBeaconBlock::Capella(block));
+            }
+            Ok(SignedBlindedBeaconBlock::Bellatrix(deserialize(bytes)?))
+        }
+    }
 }
 
 // STATE SYNC
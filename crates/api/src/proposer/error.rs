@@ -6,7 +6,7 @@ use axum::{
 };
 use ethereum_consensus::{
     primitives::{BlsPublicKey, ExecutionAddress, Hash32, Slot},
-    ssz::prelude::MerkleizationError,
+    ssz::prelude::{DeserializeError, MerkleizationError, SerializeError},
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -65,6 +65,15 @@ pub enum ProposerApiError {
     #[error("serde decode error: {0}")]
     SerdeDecodeError(#[from] serde_json::Error),
 
+    #[error("ssz deserialize error: {0}")]
+    SszDeserializeError(#[from] DeserializeError),
+
+    #[error("ssz serialize error: {0}")]
+    SszSerializeError(#[from] SerializeError),
+
+    #[error("payload too large. max size: {max_size}, size: {size}")]
+    PayloadTooLarge { max_size: usize, size: usize },
+
     #[error("block does not match the provided header")]
     UnknownBlock,
 
@@ -158,6 +167,9 @@ pub enum ProposerApiError {
     #[error("number of blinded blobs does not match blobs bundle length")]
     BlindedBlobsBundleLengthMismatch,
 
+    #[error("blinded block blob kzg commitments do not match blobs bundle commitments")]
+    BlindedBlobsBundleCommitmentsMismatch,
+
     #[error("internal slot: {internal_slot} does not match slot duty slot: {slot_duty_slot}")]
     InternalSlotMismatchesWithSlotDuty { internal_slot: u64, slot_duty_slot: u64 },
 
@@ -174,6 +186,21 @@ pub enum ProposerApiError {
 
     #[error("parent hash unknown for slot: {slot}")]
     ParentHashUnknownForSlot { slot: u64 },
+
+    #[error("too many top bid subscriptions")]
+    TooManyTopBidSubscriptions,
+
+    #[error("auctioneer unavailable")]
+    AuctioneerUnavailable,
+
+    #[error("no payload endpoint known for the builder of this bid")]
+    NoBuilderPayloadEndpoint,
+
+    #[error("builder payload request failed: {0}")]
+    BuilderPayloadRequestError(#[from] reqwest::Error),
+
+    #[error("builder payload block hash mismatch. expected: {expected:?}, got: {got:?}")]
+    BuilderPayloadBlockHashMismatch { expected: Hash32, got: Hash32 },
 }
 
 impl IntoResponse for ProposerApiError {
@@ -227,6 +254,15 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::SerdeDecodeError(err) => {
                 (StatusCode::BAD_REQUEST, format!("Serde decode error: {err}")).into_response()
             },
+            ProposerApiError::SszDeserializeError(err) => {
+                (StatusCode::BAD_REQUEST, format!("SSZ deserialize error: {err}")).into_response()
+            },
+            ProposerApiError::SszSerializeError(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("SSZ serialize error: {err}")).into_response()
+            },
+            ProposerApiError::PayloadTooLarge { max_size, size } => {
+                (StatusCode::PAYLOAD_TOO_LARGE, format!("Payload too large. max size: {max_size}, size: {size}")).into_response()
+            },
             ProposerApiError::UnknownBlock => {
                 (StatusCode::BAD_REQUEST, "Block does not match the provided header").into_response()
             },
@@ -320,6 +356,9 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::BlindedBlobsBundleLengthMismatch => {
                 (StatusCode::BAD_REQUEST, "number of blinded blobs does not match blobs bundle length").into_response()
             },
+            ProposerApiError::BlindedBlobsBundleCommitmentsMismatch => {
+                (StatusCode::BAD_REQUEST, "blinded block blob kzg commitments do not match blobs bundle commitments").into_response()
+            },
             ProposerApiError::InternalSlotMismatchesWithSlotDuty {internal_slot, slot_duty_slot} => {
                 (
                     StatusCode::BAD_REQUEST,
@@ -341,6 +380,21 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::ParentHashUnknownForSlot {slot} => {
                 (StatusCode::BAD_REQUEST, format!("parent hash unknown for slot: {slot}")).into_response()
             },
+            ProposerApiError::TooManyTopBidSubscriptions => {
+                (StatusCode::TOO_MANY_REQUESTS, "too many top bid subscriptions").into_response()
+            },
+            ProposerApiError::AuctioneerUnavailable => {
+                (StatusCode::SERVICE_UNAVAILABLE, "auctioneer unavailable").into_response()
+            },
+            ProposerApiError::NoBuilderPayloadEndpoint => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "No payload endpoint known for the builder of this bid").into_response()
+            },
+            ProposerApiError::BuilderPayloadRequestError(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("Builder payload request failed: {err}")).into_response()
+            },
+            ProposerApiError::BuilderPayloadBlockHashMismatch { expected, got } => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("Builder payload block hash mismatch. expected: {expected:?}, got: {got:?}")).into_response()
+            },
         }
     }
 }
@@ -1,5 +1,6 @@
 pub mod api;
 pub mod error;
+pub mod payload_fallback;
 #[cfg(test)]
 pub mod tests;
 pub mod types;
@@ -0,0 +1,69 @@
+use std::{collections::HashMap, time::Duration};
+
+use ethereum_consensus::primitives::{BlsPublicKey, Hash32};
+use helix_common::{versioned_payload::PayloadAndBlobs, BuilderPayloadFallbackConfig};
+use helix_datastore::Auctioneer;
+
+use crate::proposer::error::ProposerApiError;
+
+/// Recovers payloads missing from the datastore, e.g. after a relay restart, by requesting them
+/// from the builder that submitted the bid.
+pub struct BuilderPayloadFallback {
+    http: reqwest::Client,
+    endpoints: HashMap<BlsPublicKey, String>,
+}
+
+impl BuilderPayloadFallback {
+    pub fn new(config: &BuilderPayloadFallbackConfig) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .expect("failed to build builder payload fallback client");
+        let endpoints = config
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint.builder_pub_key.clone(), endpoint.url.clone()))
+            .collect();
+        Self { http, endpoints }
+    }
+
+    /// Fetches the payload for `block_hash` from the builder that submitted the bid.
+    ///
+    /// The builder is looked up from the bid trace saved at submission time. Payloads whose block
+    /// hash does not match the requested one are rejected.
+    pub async fn fetch_payload<A: Auctioneer>(
+        &self,
+        auctioneer: &A,
+        slot: u64,
+        proposer_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+    ) -> Result<PayloadAndBlobs, ProposerApiError> {
+        let bid_trace = auctioneer
+            .get_bid_trace(slot, proposer_pub_key, block_hash)
+            .await?
+            .ok_or(ProposerApiError::NoBuilderPayloadEndpoint)?;
+        let url = self
+            .endpoints
+            .get(&bid_trace.builder_public_key)
+            .ok_or(ProposerApiError::NoBuilderPayloadEndpoint)?;
+
+        let payload: PayloadAndBlobs = self
+            .http
+            .get(format!("{url}/{slot}/{block_hash}"))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let payload_block_hash = payload.execution_payload.block_hash();
+        if payload_block_hash != block_hash {
+            return Err(ProposerApiError::BuilderPayloadBlockHashMismatch {
+                expected: block_hash.clone(),
+                got: payload_block_hash.clone(),
+            });
+        }
+
+        Ok(payload)
+    }
+}
@@ -33,8 +33,13 @@ mod proposer_api_tests {
     // +++ IMPORTS +++
     use crate::{
         gossiper::{mock_gossiper::MockGossiper, types::GossipedMessage}, proposer::{
-            api::{get_nanos_timestamp, ProposerApi},
-            PATH_GET_PAYLOAD, PATH_PROPOSER_API,
+            api::{
+                deserialize_get_payload_bytes, get_nanos_timestamp, ProposerApi,
+                MAX_BLINDED_BLOCK_LENGTH,
+            },
+            error::ProposerApiError,
+            payload_fallback::BuilderPayloadFallback,
+            unblind_beacon_block, PATH_GET_PAYLOAD, PATH_PROPOSER_API,
         }, test_utils::proposer_api_app
     };
 
@@ -42,9 +47,12 @@ mod proposer_api_tests {
         bellatrix,
         builder::{SignedValidatorRegistration, ValidatorRegistration},
         capella::mainnet::{BlindedBeaconBlockBody, ExecutionPayloadHeader},
-        deneb::SyncAggregate,
+        deneb::{
+            polynomial_commitments::{KzgCommitment, KzgProof},
+            SyncAggregate,
+        },
         phase0::Eth1Data,
-        primitives::{BlsPublicKey, BlsSignature},
+        primitives::{BlsPublicKey, BlsSignature, Hash32},
         ssz::prelude::*,
     };
     use rand::Rng;
@@ -56,22 +64,32 @@ mod proposer_api_tests {
     use helix_beacon_client::mock_multi_beacon_client::MockMultiBeaconClient;
     use helix_common::{
         api::{
-            builder_api::BuilderGetValidatorsResponseEntry, proposer_api::ValidatorRegistrationInfo,
+            builder_api::{BuilderGetValidatorsResponseEntry, TopBidUpdate},
+            proposer_api::ValidatorRegistrationInfo,
         },
         capella::{self},
         deneb::{self},
         chain_info::ChainInfo,
+        bid_submission::BidTrace,
+        signed_proposal::VersionedSignedProposal,
         versioned_payload::PayloadAndBlobs,
-        SignedBuilderBid, ValidatorPreferences,
+        BidRequest, BuilderPayloadEndpoint, BuilderPayloadFallbackConfig, SignedBuilderBid,
+        ValidatorPreferences,
     };
     use helix_database::MockDatabaseService;
     use helix_datastore::MockAuctioneer;
     use helix_housekeeper::{ChainUpdate, PayloadAttributesUpdate, SlotUpdate};
     use helix_utils::{request_encoding::Encoding, signing::verify_signed_consensus_message};
     use serial_test::serial;
-    use std::{sync::Arc, time::Duration};
+    use axum::{body::Body, http::Request};
+    use std::{
+        sync::Arc,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use uuid::Uuid;
     use tokio::{
         sync::{
+            broadcast,
             mpsc::{channel, Receiver, Sender},
             oneshot,
         },
@@ -210,6 +228,7 @@ mod proposer_api_tests {
             parent_hash: Default::default(),
             withdrawals_root: Default::default(),
             payload_attributes: Default::default(),
+            parent_gas_limit: None,
         });
         slot_update_sender.send(chain_update).await.unwrap();
 
@@ -257,6 +276,20 @@ mod proposer_api_tests {
         time_since_genesis / seconds_per_slot
     }
 
+    async fn next_top_bid_event(resp: &mut Response) -> SignedBuilderBid {
+        loop {
+            let chunk = tokio::time::timeout(Duration::from_secs(5), resp.chunk())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+            if let Some(data) = chunk.lines().find_map(|line| line.strip_prefix("data:")) {
+                return serde_json::from_str(data.trim_start()).unwrap();
+            }
+        }
+    }
+
     fn get_signed_builder_bid(value: U256) -> SignedBuilderBid {
         SignedBuilderBid::Capella(capella::SignedBuilderBid {
             message: helix_common::eth::capella::BuilderBid { value, ..Default::default() },
@@ -596,6 +629,204 @@ mod proposer_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_get_header_json_and_ssz_encodings() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, auctioneer) =
+            start_api_server().await;
+
+        // Set a SignedBuilderBid in the auctioneer
+        let builder_bid = get_signed_builder_bid(U256::from(10));
+        let _ = auctioneer.best_bid.lock().unwrap().insert(builder_bid.clone());
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None, None).await;
+
+        let current_slot = calculate_current_slot();
+        let req_url = format!(
+            "{}{}/header/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            current_slot + 1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        // JSON encoded response
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/json");
+        assert_eq!(resp.headers().get("eth-consensus-version").unwrap(), "capella");
+        let json_bid: SignedBuilderBid = serde_json::from_slice(&resp.bytes().await.unwrap()).unwrap();
+
+        // SSZ encoded response
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/octet-stream;q=1.0,application/json;q=0.9")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/octet-stream");
+        assert_eq!(resp.headers().get("eth-consensus-version").unwrap(), "capella");
+        let ssz_bid: capella::SignedBuilderBid = deserialize(&resp.bytes().await.unwrap()).unwrap();
+        let ssz_bid = SignedBuilderBid::Capella(ssz_bid);
+
+        assert_eq!(serde_json::to_value(&ssz_bid).unwrap(), serde_json::to_value(&json_bid).unwrap());
+        assert_eq!(ssz_bid.value(), builder_bid.value());
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    // SUBSCRIBE_TOP_BID
+    #[tokio::test]
+    #[serial]
+    async fn test_subscribe_top_bid_for_past_slot() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, _auctioneer) =
+            start_api_server().await;
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None, None).await;
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}/top_bid/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        let resp = reqwest::Client::new().get(req_url.as_str()).send().await.unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            "request for past slot. request slot: 1, head slot: 32"
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_subscribe_top_bid_new_top_bid() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, auctioneer) =
+            start_api_server().await;
+
+        // Stream top bid updates from a channel controlled by the test
+        let (best_bids_tx, _best_bids_rx) = broadcast::channel(16);
+        let _ = auctioneer.best_bids_tx.lock().unwrap().insert(best_bids_tx.clone());
+
+        // Set a SignedBuilderBid in the auctioneer
+        let builder_bid = get_signed_builder_bid(U256::from(10));
+        let _ = auctioneer.best_bid.lock().unwrap().insert(builder_bid);
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None, None).await;
+
+        let current_slot = calculate_current_slot();
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}/top_bid/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            current_slot + 1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        let mut resp = reqwest::Client::new().get(req_url.as_str()).send().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // The current best bid is sent on subscribe
+        let bid = next_top_bid_event(&mut resp).await;
+        assert_eq!(bid.value(), U256::from(10));
+
+        // A new winning bid is selected
+        let _ = auctioneer.best_bid.lock().unwrap().insert(get_signed_builder_bid(U256::from(20)));
+        let top_bid_update = TopBidUpdate {
+            slot: current_slot + 1,
+            parent_hash: get_byte_vector_32_for_hex(PARENT_HASH),
+            value: U256::from(20),
+            ..Default::default()
+        };
+        best_bids_tx.send(serialize(&top_bid_update).unwrap()).unwrap();
+
+        let bid = next_top_bid_event(&mut resp).await;
+        assert_eq!(bid.value(), U256::from(20));
+
+        // Shut down the server
+        drop(resp);
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_subscribe_top_bid_lagged_updates_resend_best_bid() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, auctioneer) =
+            start_api_server().await;
+
+        // A single update fits in the channel, so a burst of updates lags the subscriber
+        let (best_bids_tx, _best_bids_rx) = broadcast::channel(1);
+        let _ = auctioneer.best_bids_tx.lock().unwrap().insert(best_bids_tx.clone());
+        let _ = auctioneer.best_bid.lock().unwrap().insert(get_signed_builder_bid(U256::from(10)));
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None, None).await;
+
+        let current_slot = calculate_current_slot();
+        let req_url = format!(
+            "{}{}/top_bid/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            current_slot + 1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        let mut resp = reqwest::Client::new().get(req_url.as_str()).send().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let bid = next_top_bid_event(&mut resp).await;
+        assert_eq!(bid.value(), U256::from(10));
+
+        // The update for our slot is lost, only an unrelated one is left in the channel
+        let _ = auctioneer.best_bid.lock().unwrap().insert(get_signed_builder_bid(U256::from(20)));
+        for slot in [current_slot + 1, current_slot + 2, current_slot + 2] {
+            let top_bid_update = TopBidUpdate {
+                slot,
+                parent_hash: get_byte_vector_32_for_hex(PARENT_HASH),
+                value: U256::from(20),
+                ..Default::default()
+            };
+            best_bids_tx.send(serialize(&top_bid_update).unwrap()).unwrap();
+        }
+
+        let bid = next_top_bid_event(&mut resp).await;
+        assert_eq!(bid.value(), U256::from(20));
+
+        // Shut down the server
+        drop(resp);
+        let _ = tx.send(());
+    }
+
     // GET_PAYLOAD
     #[tokio::test]
     #[serial]
@@ -1004,6 +1235,9 @@ mod proposer_api_tests {
                 slot_update_sender.clone(),
                 Arc::new(ValidatorPreferences::default()),
                 0,
+                None,
+                0,
+                None,
                 gossip_receiver,
             );
 
@@ -1012,6 +1246,166 @@ mod proposer_api_tests {
         prop_api.validate_registration(&mut x).unwrap();
     }
 
+    fn get_test_proposer_api(
+    ) -> ProposerApi<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper> {
+        get_test_proposer_api_with_chain_info(ChainInfo::for_mainnet())
+    }
+
+    fn get_test_proposer_api_with_chain_info(
+        chain_info: ChainInfo,
+    ) -> ProposerApi<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper> {
+        get_test_proposer_api_with_cutoff(chain_info, None, 0)
+    }
+
+    fn get_test_proposer_api_with_cutoff(
+        chain_info: ChainInfo,
+        get_header_request_cutoff_ms: Option<u64>,
+        clock_skew_tolerance_ms: u64,
+    ) -> ProposerApi<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper> {
+        let (slot_update_sender, _slot_update_receiver) = channel::<Sender<ChainUpdate>>(32);
+        let (_gossip_sender, gossip_receiver) = channel::<GossipedMessage>(32);
+
+        ProposerApi::<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper>::new(
+            Arc::new(MockAuctioneer::default()),
+            Arc::new(MockDatabaseService::default()),
+            Arc::new(MockGossiper::new().unwrap()),
+            vec![],
+            Arc::new(MockMultiBeaconClient::default()),
+            Arc::new(chain_info),
+            slot_update_sender,
+            Arc::new(ValidatorPreferences::default()),
+            0,
+            get_header_request_cutoff_ms,
+            clock_skew_tolerance_ms,
+            None,
+            gossip_receiver,
+        )
+    }
+
+    /// Returns chain info where slot 0 started between 1 and 2 seconds ago.
+    fn get_chain_info_with_slot_duration(seconds_per_slot: u64) -> ChainInfo {
+        let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        ChainInfo {
+            seconds_per_slot,
+            genesis_time_in_secs: now_secs - 1,
+            ..ChainInfo::for_mainnet()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_header_cutoff_mainnet_slot() {
+        let chain_info = get_chain_info_with_slot_duration(12);
+        assert_eq!(chain_info.get_header_request_cutoff_ms(), 3000);
+        let prop_api = get_test_proposer_api_with_chain_info(chain_info);
+
+        let bid_request = BidRequest { slot: 0, ..Default::default() };
+        assert!(prop_api.validate_bid_request_time(&bid_request).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_header_cutoff_short_slot() {
+        let chain_info = get_chain_info_with_slot_duration(2);
+        assert_eq!(chain_info.get_header_request_cutoff_ms(), 500);
+        let prop_api = get_test_proposer_api_with_chain_info(chain_info);
+
+        let bid_request = BidRequest { slot: 0, ..Default::default() };
+        match prop_api.validate_bid_request_time(&bid_request) {
+            Err(ProposerApiError::GetHeaderRequestTooLate { ms_into_slot, cutoff }) => {
+                assert_eq!(cutoff, 500);
+                assert!(ms_into_slot >= 1000);
+            }
+            res => panic!("expected get header request too late, got {res:?}"),
+        }
+    }
+
+    /// Returns chain info where slot 0 started 3 to 4 seconds ago, and how many ms into slot 0 it
+    /// is now. Far enough into the slot for the cutoffs below it to never underflow.
+    fn get_chain_info_with_slot_start() -> (ChainInfo, u64) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let chain_info =
+            ChainInfo { genesis_time_in_secs: now.as_secs() - 3, ..ChainInfo::for_mainnet() };
+        let ms_into_slot = now.as_millis() as u64 - chain_info.genesis_time_in_secs * 1000;
+        (chain_info, ms_into_slot)
+    }
+
+    #[tokio::test]
+    async fn test_get_header_late_within_clock_skew_tolerance_is_accepted() {
+        let (chain_info, ms_into_slot) = get_chain_info_with_slot_start();
+        assert_eq!(chain_info.max_clock_skew_tolerance_ms(), 1000);
+        // Late by ~200ms with a 500ms tolerance
+        let prop_api =
+            get_test_proposer_api_with_cutoff(chain_info, Some(ms_into_slot - 200), 500);
+
+        let bid_request = BidRequest { slot: 0, ..Default::default() };
+        assert!(prop_api.validate_bid_request_time(&bid_request).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_header_late_beyond_clock_skew_tolerance_is_rejected() {
+        let (chain_info, ms_into_slot) = get_chain_info_with_slot_start();
+        // Late by ~800ms with a 500ms tolerance
+        let cutoff = ms_into_slot - 800;
+        let prop_api = get_test_proposer_api_with_cutoff(chain_info, Some(cutoff), 500);
+
+        let bid_request = BidRequest { slot: 0, ..Default::default() };
+        match prop_api.validate_bid_request_time(&bid_request) {
+            Err(ProposerApiError::GetHeaderRequestTooLate { cutoff: got, .. }) => {
+                assert_eq!(got, cutoff);
+            }
+            res => panic!("expected get header request too late, got {res:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_tolerance_is_capped() {
+        let (chain_info, ms_into_slot) = get_chain_info_with_slot_start();
+        // Late by ~1500ms, within the configured tolerance but beyond the cap of a twelfth of the slot
+        let prop_api =
+            get_test_proposer_api_with_cutoff(chain_info, Some(ms_into_slot - 1500), 5000);
+
+        let bid_request = BidRequest { slot: 0, ..Default::default() };
+        assert!(matches!(
+            prop_api.validate_bid_request_time(&bid_request),
+            Err(ProposerApiError::GetHeaderRequestTooLate { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_registrations_drops_invalid_signature() {
+        let prop_api = get_test_proposer_api();
+
+        let mut registrations: Vec<SignedValidatorRegistration> =
+            (0..5).map(|_| gen_signed_vr()).collect();
+        // Sign the third registration with a different key
+        registrations[2].signature = gen_signed_vr().signature;
+        let bad_pub_key = registrations[2].message.public_key.clone();
+
+        let (valid, failed) =
+            prop_api.verify_registrations(registrations, &Uuid::new_v4()).await.unwrap();
+
+        assert_eq!(valid.len(), 4);
+        assert!(valid.iter().all(|r| r.message.public_key != bad_pub_key));
+        assert_eq!(failed, vec![bad_pub_key]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore]
+    async fn bench_verify_registrations_10k() {
+        let prop_api = get_test_proposer_api();
+        let registrations: Vec<SignedValidatorRegistration> =
+            (0..10_000).map(|_| gen_signed_vr()).collect();
+
+        let start = std::time::Instant::now();
+        let (valid, failed) =
+            prop_api.verify_registrations(registrations, &Uuid::new_v4()).await.unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(valid.len(), 10_000);
+        assert!(failed.is_empty());
+        // Verifying sequentially takes roughly a millisecond per signature
+        assert!(elapsed < Duration::from_secs(5), "verified 10k registrations in {elapsed:?}");
+    }
+
     #[test]
     fn test_verify_signed_blinded_block_signature_from_file_deneb() {
 
@@ -1057,4 +1451,219 @@ mod proposer_api_tests {
         }
         
     }
+
+    fn get_ssz_get_payload_request(
+        body: Vec<u8>,
+        consensus_version: Option<&str>,
+    ) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method("POST")
+            .header("Content-Type", "application/octet-stream")
+            .header("Content-Length", body.len());
+        if let Some(consensus_version) = consensus_version {
+            builder = builder.header("Eth-Consensus-Version", consensus_version);
+        }
+        builder.body(Body::from(body)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_get_payload_bytes_ssz_capella() {
+        let mut signed_blinded_beacon_block = capella::SignedBlindedBeaconBlock::default();
+        signed_blinded_beacon_block.message.slot = 10;
+        let body = serialize(&signed_blinded_beacon_block).unwrap();
+
+        let req = get_ssz_get_payload_request(body, Some("capella"));
+        let decoded = deserialize_get_payload_bytes(req).await.unwrap();
+
+        assert!(matches!(decoded, SignedBlindedBeaconBlock::Capella(_)));
+        assert_eq!(decoded.message().slot(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_get_payload_bytes_ssz_deneb() {
+        let mut signed_blinded_beacon_block = deneb::SignedBlindedBeaconBlock::default();
+        signed_blinded_beacon_block.message.slot = 20;
+        let body = serialize(&signed_blinded_beacon_block).unwrap();
+
+        let req = get_ssz_get_payload_request(body.clone(), Some("deneb"));
+        let decoded = deserialize_get_payload_bytes(req).await.unwrap();
+        assert!(matches!(decoded, SignedBlindedBeaconBlock::Deneb(_)));
+        assert_eq!(decoded.message().slot(), 20);
+
+        // Without a version header the newest matching fork is used
+        let req = get_ssz_get_payload_request(body, None);
+        let decoded = deserialize_get_payload_bytes(req).await.unwrap();
+        assert!(matches!(decoded, SignedBlindedBeaconBlock::Deneb(_)));
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_get_payload_bytes_ssz_too_large() {
+        let body = vec![0u8; MAX_BLINDED_BLOCK_LENGTH + 1];
+        let req = get_ssz_get_payload_request(body, Some("deneb"));
+        let result = deserialize_get_payload_bytes(req).await;
+
+        match result {
+            Err(ProposerApiError::PayloadTooLarge { max_size, size }) => {
+                assert_eq!(max_size, MAX_BLINDED_BLOCK_LENGTH);
+                assert_eq!(size, MAX_BLINDED_BLOCK_LENGTH + 1);
+            }
+            _ => panic!("expected payload too large error"),
+        }
+    }
+
+    fn get_deneb_payload_and_blobs(num_blobs: u8) -> PayloadAndBlobs {
+        let mut blobs_bundle = deneb::BlobsBundle::default();
+        for i in 0..num_blobs {
+            blobs_bundle.commitments.push(KzgCommitment::try_from([i + 1; 48].as_ref()).unwrap());
+            blobs_bundle.proofs.push(KzgProof::try_from([i + 1; 48].as_ref()).unwrap());
+            blobs_bundle.blobs.push(deneb::Blob::default());
+        }
+
+        PayloadAndBlobs {
+            execution_payload: ExecutionPayload::Deneb(deneb::ExecutionPayload::default()),
+            blobs_bundle: Some(blobs_bundle),
+        }
+    }
+
+    #[test]
+    fn test_unblind_deneb_block_with_blobs() {
+        let payload_and_blobs = get_deneb_payload_and_blobs(2);
+
+        let mut blinded_block = deneb::SignedBlindedBeaconBlock::default();
+        blinded_block.message.body.blob_kzg_commitments =
+            payload_and_blobs.blobs_bundle.as_ref().unwrap().commitments.clone();
+        let signed_blinded_beacon_block = SignedBlindedBeaconBlock::Deneb(blinded_block);
+
+        let unblinded =
+            unblind_beacon_block(&signed_blinded_beacon_block, &payload_and_blobs).unwrap();
+        match unblinded {
+            VersionedSignedProposal::Deneb(block_contents) => {
+                let blobs_bundle = payload_and_blobs.blobs_bundle.unwrap();
+                assert_eq!(block_contents.blobs.len(), 2);
+                assert_eq!(block_contents.kzg_proofs, blobs_bundle.proofs);
+                assert_eq!(block_contents.blobs, blobs_bundle.blobs);
+            }
+            _ => panic!("expected deneb signed block contents"),
+        }
+    }
+
+    #[test]
+    fn test_unblind_deneb_block_commitments_mismatch() {
+        let payload_and_blobs = get_deneb_payload_and_blobs(2);
+
+        let mut blinded_block = deneb::SignedBlindedBeaconBlock::default();
+        blinded_block.message.body.blob_kzg_commitments.push(KzgCommitment::default());
+        blinded_block.message.body.blob_kzg_commitments.push(KzgCommitment::default());
+        let signed_blinded_beacon_block = SignedBlindedBeaconBlock::Deneb(blinded_block);
+
+        let result = unblind_beacon_block(&signed_blinded_beacon_block, &payload_and_blobs);
+        assert!(matches!(result, Err(ProposerApiError::BlindedBlobsBundleCommitmentsMismatch)));
+    }
+
+    fn get_test_payload_fallback(builder_url: String) -> BuilderPayloadFallback {
+        BuilderPayloadFallback::new(&BuilderPayloadFallbackConfig {
+            endpoints: vec![BuilderPayloadEndpoint {
+                builder_pub_key: BlsPublicKey::default(),
+                url: builder_url,
+            }],
+            timeout_ms: 500,
+        })
+    }
+
+    fn get_test_proposer_api_with_payload_fallback(
+        auctioneer: Arc<MockAuctioneer>,
+        builder_url: String,
+    ) -> ProposerApi<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper> {
+        let (slot_update_sender, _slot_update_receiver) = channel::<Sender<ChainUpdate>>(32);
+        let (_gossip_sender, gossip_receiver) = channel::<GossipedMessage>(32);
+        let payload_fallback = get_test_payload_fallback(builder_url);
+
+        ProposerApi::<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper>::new(
+            auctioneer,
+            Arc::new(MockDatabaseService::default()),
+            Arc::new(MockGossiper::new().unwrap()),
+            vec![],
+            Arc::new(MockMultiBeaconClient::default()),
+            Arc::new(ChainInfo::for_mainnet()),
+            slot_update_sender,
+            Arc::new(ValidatorPreferences::default()),
+            0,
+            None,
+            0,
+            Some(Arc::new(payload_fallback)),
+            gossip_receiver,
+        )
+    }
+
+    fn get_payload_with_block_hash(block_hash: &Hash32) -> PayloadAndBlobs {
+        let mut execution_payload = deneb::ExecutionPayload::default();
+        execution_payload.block_hash = block_hash.clone();
+        PayloadAndBlobs {
+            execution_payload: ExecutionPayload::Deneb(execution_payload),
+            blobs_bundle: None,
+        }
+    }
+
+    /// Serves `builder_payload` from a mock builder, expecting `expected_hits` requests, for a bid
+    /// whose payload is missing from the returned auctioneer.
+    fn serve_builder_payload(
+        block_hash: &Hash32,
+        builder_payload: &PayloadAndBlobs,
+        expected_hits: usize,
+    ) -> (mockito::ServerGuard, mockito::Mock, Arc<MockAuctioneer>) {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", format!("/0/{block_hash}").as_str())
+            .with_status(200)
+            .with_body(serde_json::to_string(builder_payload).unwrap())
+            .expect(expected_hits)
+            .create();
+
+        let auctioneer = Arc::new(MockAuctioneer::default());
+        let bid_trace = BidTrace { block_hash: block_hash.clone(), ..Default::default() };
+        *auctioneer.bid_trace.lock().unwrap() = Some(bid_trace);
+        (server, mock, auctioneer)
+    }
+
+    #[tokio::test]
+    async fn test_get_execution_payload_falls_back_to_builder() {
+        let block_hash = Hash32::try_from([1u8; 32].as_ref()).unwrap();
+        let builder_payload = get_payload_with_block_hash(&block_hash);
+        let (server, mock, auctioneer) = serve_builder_payload(&block_hash, &builder_payload, 1);
+        let prop_api = get_test_proposer_api_with_payload_fallback(auctioneer, server.url());
+
+        let payload = prop_api
+            .get_execution_payload(0, &BlsPublicKey::default(), &block_hash, &Uuid::new_v4())
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(payload.execution_payload.block_hash(), &block_hash);
+    }
+
+    #[tokio::test]
+    async fn test_get_execution_payload_rejects_mismatched_builder_payload() {
+        let block_hash = Hash32::try_from([1u8; 32].as_ref()).unwrap();
+        let other_block_hash = Hash32::try_from([2u8; 32].as_ref()).unwrap();
+        let builder_payload = get_payload_with_block_hash(&other_block_hash);
+        let (server, mock, auctioneer) = serve_builder_payload(&block_hash, &builder_payload, 2);
+
+        // The fallback rejects the builder's payload
+        let payload_fallback = get_test_payload_fallback(server.url());
+        let result = payload_fallback
+            .fetch_payload(auctioneer.as_ref(), 0, &BlsPublicKey::default(), &block_hash)
+            .await;
+        assert!(matches!(
+            result,
+            Err(ProposerApiError::BuilderPayloadBlockHashMismatch { expected, got })
+                if expected == block_hash && got == other_block_hash
+        ));
+
+        // So the payload is never returned to the proposer
+        let prop_api = get_test_proposer_api_with_payload_fallback(auctioneer, server.url());
+        let result = prop_api
+            .get_execution_payload(0, &BlsPublicKey::default(), &block_hash, &Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Err(ProposerApiError::NoExecutionPayloadFound)));
+        mock.assert();
+    }
 }
@@ -16,8 +16,7 @@ pub(crate) const PATH_STATUS: &str = "/status";
 pub(crate) const PATH_REGISTER_VALIDATORS: &str = "/validators";
 pub(crate) const PATH_GET_HEADER: &str = "/header/:slot/:parent_hash/:pubkey";
 pub(crate) const PATH_GET_PAYLOAD: &str = "/blinded_blocks";
-
-pub(crate) const GET_HEADER_REQUEST_CUTOFF_MS: i64 = 3000;
+pub(crate) const PATH_SUBSCRIBE_TOP_BID: &str = "/top_bid/:slot/:parent_hash/:pubkey";
 
 #[derive(Debug, Deserialize)]
 pub struct GetHeaderParams {
@@ -114,6 +113,10 @@ pub fn unblind_beacon_block(
                 return Err(ProposerApiError::BlindedBlobsBundleLengthMismatch);
             }
 
+            if body.blob_kzg_commitments != blobs_bundle.commitments {
+                return Err(ProposerApiError::BlindedBlobsBundleCommitmentsMismatch);
+            }
+
             let inner = deneb::SignedBeaconBlock {
                 message: deneb::BeaconBlock {
                     slot: block.slot,
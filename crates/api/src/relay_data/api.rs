@@ -1,26 +1,52 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use axum::{
+    body::{Body, Bytes},
     extract::{Extension, Query},
+    http::header,
     response::IntoResponse,
     Json,
 };
+use futures::StreamExt;
 use moka::sync::Cache;
+use tower::BoxError;
 use tracing::warn;
 
 use helix_common::{api::data_api::{
-    BuilderBlocksReceivedParams, DeliveredPayloadsResponse, ProposerPayloadDeliveredParams,
-    ReceivedBlocksResponse, ValidatorRegistrationParams,
-}, validator_preferences, ValidatorPreferences};
+    BidFilters, BidsReceivedParams, BidsReceivedResponse, BuilderBlocksReceivedParams,
+    BuilderReputationParams, DeliveredPayloadsResponse, ExportDeliveredPayloadsParams,
+    ProposerPayloadDeliveredParams,
+    ReceivedBlocksResponse, RecordedTracesParams, ValidatorRegistrationParams,
+}, validator_preferences, TraceRecordResponse, ValidatorPreferences};
 use helix_database::DatabaseService;
 
-use crate::relay_data::error::DataApiError;
+use crate::{
+    builder::{reputation::BuilderReputationStore, trace_recorder::TraceRecorder},
+    relay_data::error::DataApiError,
+};
 
 pub(crate) const PATH_DATA_API: &str = "/relay/v1/data";
 
 pub(crate) const PATH_PROPOSER_PAYLOAD_DELIVERED: &str = "/bidtraces/proposer_payload_delivered";
 pub(crate) const PATH_BUILDER_BIDS_RECEIVED: &str = "/bidtraces/builder_blocks_received";
 pub(crate) const PATH_VALIDATOR_REGISTRATION: &str = "/validator_registration";
+pub(crate) const PATH_BUILDER_REPUTATION: &str = "/builder_reputation";
+pub(crate) const PATH_BIDS_RECEIVED: &str = "/bids_received";
+
+/// Maximum number of records returned per page.
+pub(crate) const MAX_LIMIT: u64 = 500;
+/// Maximum number of slots a delivered payloads slot range query may span.
+pub(crate) const MAX_SLOT_RANGE: u64 = 7200;
+/// Number of slots fetched from the database per chunk of a delivered payloads export.
+pub(crate) const EXPORT_CHUNK_SLOTS: u64 = 1000;
+/// Maximum number of slots a delivered payloads export may span.
+pub(crate) const MAX_EXPORT_SLOT_RANGE: u64 = 30 * MAX_SLOT_RANGE;
+/// Highest slot a delivered payloads export may include, slots are filtered as `i32` in the
+/// database.
+pub(crate) const MAX_EXPORT_SLOT: u64 = i32::MAX as u64;
 
 pub(crate) type BidsCache = Cache<String, Vec<ReceivedBlocksResponse>>;
 pub(crate) type DeliveredPayloadsCache = Cache<String, Vec<DeliveredPayloadsResponse>>;
@@ -43,15 +69,34 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
     }
 
     /// Implements this API: <https://flashbots.github.io/relay-specs/#/Data/getDeliveredPayloads>
+    ///
+    /// Additionally supports an inclusive `from_slot`/`to_slot` range of at most
+    /// `MAX_SLOT_RANGE` slots. Results are capped at `MAX_LIMIT` records per page.
     pub async fn proposer_payload_delivered(
         Extension(data_api): Extension<Arc<DataApi<DB>>>,
         Extension(cache): Extension<Arc<DeliveredPayloadsCache>>,
-        Query(params): Query<ProposerPayloadDeliveredParams>,
+        Query(mut params): Query<ProposerPayloadDeliveredParams>,
     ) -> Result<impl IntoResponse, DataApiError> {
         if params.slot.is_some() && params.cursor.is_some() {
             return Err(DataApiError::SlotAndCursor);
         }
 
+        if params.limit.is_some_and(|limit| limit > MAX_LIMIT) {
+            return Err(DataApiError::LimitReached);
+        }
+
+        match (params.from_slot, params.to_slot) {
+            (None, None) => {}
+            (Some(from_slot), Some(to_slot)) if from_slot <= to_slot => {
+                if to_slot - from_slot >= MAX_SLOT_RANGE {
+                    return Err(DataApiError::SlotRangeTooLarge { max: MAX_SLOT_RANGE });
+                }
+            }
+            _ => return Err(DataApiError::InvalidSlotRange),
+        }
+
+        params.limit = Some(params.limit.unwrap_or(MAX_LIMIT));
+
         let cache_key = format!("{:?}", params);
 
         if let Some(cached_result) = cache.get(&cache_key) {
@@ -90,7 +135,7 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
             return Err(DataApiError::MissingFilter);
         }
 
-        if params.limit.is_some() && params.limit.unwrap() > 500 {
+        if params.limit.is_some() && params.limit.unwrap() > MAX_LIMIT {
             return Err(DataApiError::LimitReached);
         }
 
@@ -116,6 +161,40 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
         }
     }
 
+    /// Returns every bid received for a slot, including the ones that did not win, ordered by
+    /// value descending. Pages hold at most `MAX_LIMIT` bids and are chained via `next_cursor`.
+    pub async fn bids_received(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<BidsReceivedParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        let limit = params.limit.unwrap_or(MAX_LIMIT).max(1);
+        if limit > MAX_LIMIT {
+            return Err(DataApiError::LimitReached);
+        }
+
+        // Fetch one extra bid to know whether there is another page
+        match data_api.db.get_bids_received(params.slot, params.cursor, limit + 1).await {
+            Ok(mut result) => {
+                result.sort_by(|a, b| b.bid_trace.value.cmp(&a.bid_trace.value));
+                let next_cursor = if result.len() as u64 > limit {
+                    result.truncate(limit as usize);
+                    result.last().map(|bid| bid.bid_trace.block_hash.clone())
+                } else {
+                    None
+                };
+
+                let bids =
+                    result.into_iter().map(|b| b.into()).collect::<Vec<ReceivedBlocksResponse>>();
+
+                Ok(Json(BidsReceivedResponse { bids, next_cursor }))
+            }
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch bids received");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+
     /// Implements this API: <https://flashbots.github.io/relay-specs/#/Data/getValidatorRegistration>
     pub async fn validator_registration(
         Extension(data_api): Extension<Arc<DataApi<DB>>>,
@@ -129,4 +208,87 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
             }
         }
     }
+
+    /// Returns the reputation the relay currently tracks for a builder.
+    pub async fn builder_reputation(
+        Extension(reputation): Extension<Arc<BuilderReputationStore>>,
+        Query(params): Query<BuilderReputationParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| DataApiError::InternalServerError)?
+            .as_millis() as u64;
+
+        Ok(Json(reputation.status(&params.pubkey, now_ms)))
+    }
+
+    /// Returns the most recently recorded submission traces, newest first, with the time spent
+    /// between consecutive checkpoints.
+    pub async fn recorded_traces(
+        Extension(recorder): Extension<Arc<TraceRecorder>>,
+        Query(params): Query<RecordedTracesParams>,
+    ) -> impl IntoResponse {
+        let records = recorder
+            .latest(params.limit.unwrap_or(usize::MAX))
+            .into_iter()
+            .map(TraceRecordResponse::from)
+            .collect::<Vec<_>>();
+        Json(records)
+    }
+
+    /// Streams every delivered payload in the inclusive `from_slot`/`to_slot` range, spanning at
+    /// most `MAX_EXPORT_SLOT_RANGE` slots up to `MAX_EXPORT_SLOT`, as newline delimited JSON,
+    /// oldest slot first. Each line has the same schema as a
+    /// `DeliveredPayloadsResponse` returned by `proposer_payload_delivered`.
+    ///
+    /// The range is fetched `EXPORT_CHUNK_SLOTS` slots at a time and each chunk is written out
+    /// before the next is fetched, so the export is never fully buffered. A database error aborts
+    /// the stream.
+    pub async fn export_delivered_payloads(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<ExportDeliveredPayloadsParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        if params.from_slot > params.to_slot {
+            return Err(DataApiError::InvalidSlotRange);
+        }
+
+        if params.to_slot > MAX_EXPORT_SLOT {
+            return Err(DataApiError::SlotTooLarge { max: MAX_EXPORT_SLOT });
+        }
+
+        if params.to_slot - params.from_slot >= MAX_EXPORT_SLOT_RANGE {
+            return Err(DataApiError::SlotRangeTooLarge { max: MAX_EXPORT_SLOT_RANGE });
+        }
+
+        let to_slot = params.to_slot;
+        let chunk_starts = (params.from_slot..=to_slot).step_by(EXPORT_CHUNK_SLOTS as usize);
+        let chunks = futures::stream::iter(chunk_starts).then(move |chunk_start| {
+            let data_api = data_api.clone();
+            async move {
+                let filters = BidFilters {
+                    from_slot: Some(chunk_start),
+                    to_slot: Some(to_slot.min(chunk_start.saturating_add(EXPORT_CHUNK_SLOTS - 1))),
+                    ..Default::default()
+                };
+                let payloads = data_api
+                    .db
+                    .get_delivered_payloads(&filters, data_api.validator_preferences.clone())
+                    .await
+                    .map_err(|err| {
+                        warn!(error=%err, chunk_start, "Failed to export delivered payloads");
+                        err
+                    })?;
+
+                // Payloads are returned newest first
+                let mut lines = Vec::new();
+                for payload in payloads.into_iter().rev() {
+                    serde_json::to_writer(&mut lines, &DeliveredPayloadsResponse::from(payload))?;
+                    lines.push(b'\n');
+                }
+                Ok::<_, BoxError>(Bytes::from(lines))
+            }
+        });
+
+        Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], Body::from_stream(chunks)))
+    }
 }
@@ -11,6 +11,12 @@ pub enum DataApiError {
     MissingFilter,
     #[error("maximum limit is 500")]
     LimitReached,
+    #[error("from_slot and to_slot must both be set and from_slot must not exceed to_slot")]
+    InvalidSlotRange,
+    #[error("maximum slot range is {max}")]
+    SlotRangeTooLarge { max: u64 },
+    #[error("maximum slot is {max}")]
+    SlotTooLarge { max: u64 },
     #[error("internal server error")]
     InternalServerError,
 }
@@ -29,6 +35,17 @@ impl IntoResponse for DataApiError {
             DataApiError::LimitReached => {
                 (StatusCode::BAD_REQUEST, "maximum limit is 500").into_response()
             }
+            DataApiError::InvalidSlotRange => (
+                StatusCode::BAD_REQUEST,
+                "from_slot and to_slot must both be set and from_slot must not exceed to_slot",
+            )
+                .into_response(),
+            DataApiError::SlotRangeTooLarge { max } => {
+                (StatusCode::BAD_REQUEST, format!("maximum slot range is {max}")).into_response()
+            }
+            DataApiError::SlotTooLarge { max } => {
+                (StatusCode::BAD_REQUEST, format!("maximum slot is {max}")).into_response()
+            }
             DataApiError::InternalServerError => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
             }
@@ -3,21 +3,32 @@ mod data_api_tests {
     // *** IMPORTS ***
     use crate::{
         relay_data::{
-            DataApi, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API, PATH_PROPOSER_PAYLOAD_DELIVERED,
+            DataApi, EXPORT_CHUNK_SLOTS, MAX_EXPORT_SLOT, MAX_EXPORT_SLOT_RANGE, MAX_SLOT_RANGE, PATH_BIDS_RECEIVED,
+            PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API, PATH_PROPOSER_PAYLOAD_DELIVERED,
             PATH_VALIDATOR_REGISTRATION,
         },
-        test_utils::data_api_app,
+        router::cors_layer,
+        test_utils::{data_api_app, data_api_app_with_layers},
+    };
+    use axum::Router;
+    use ethereum_consensus::{
+        builder::SignedValidatorRegistration,
+        primitives::{BlsPublicKey, Hash32, U256},
     };
-    use ethereum_consensus::{builder::SignedValidatorRegistration, primitives::BlsPublicKey};
     use helix_common::api::data_api::{
-        BuilderBlocksReceivedParams, DeliveredPayloadsResponse, ProposerPayloadDeliveredParams,
+        BidFilters, BidsReceivedParams, BidsReceivedResponse, BuilderBlocksReceivedParams,
+        DeliveredPayloadsResponse, ExportDeliveredPayloadsParams, ProposerPayloadDeliveredParams,
         ReceivedBlocksResponse, ValidatorRegistrationParams,
     };
-    use helix_database::MockDatabaseService;
+    use flate2::read::GzDecoder;
+    use helix_common::{
+        bid_submission::BidTrace, CorsConfig, ResponseCompressionConfig, Route,
+    };
+    use helix_database::{DeliveredPayloadDocument, MockDatabaseService};
     use helix_utils::request_encoding::Encoding;
     use reqwest::{Client, Response, StatusCode};
     use serial_test::serial;
-    use std::{sync::Arc, time::Duration};
+    use std::{io::Read, sync::Arc, time::Duration};
     use tokio::sync::oneshot;
 
     // +++ HELPER VARIABLES +++
@@ -60,12 +71,16 @@ mod data_api_tests {
         Arc<DataApi<MockDatabaseService>>,
         Arc<MockDatabaseService>,
     ) {
+        let (router, api, database) = data_api_app();
+        let (tx, http_config) = serve(router).await;
+        (tx, http_config, api, database)
+    }
+
+    async fn serve(router: Router) -> (oneshot::Sender<()>, HttpServiceConfig) {
         let (tx, rx) = oneshot::channel();
         let http_config = HttpServiceConfig::new(ADDRESS, PORT);
         let bind_address = http_config.bind_address();
 
-        let (router, api, database) = data_api_app();
-
         // Run the app in a background task
         tokio::spawn(async move {
             // run it with hyper on localhost:3000
@@ -80,13 +95,15 @@ mod data_api_tests {
 
         tokio::time::sleep(Duration::from_millis(100)).await;
 
-        (tx, http_config, api, database)
+        (tx, http_config)
     }
 
     fn get_test_proposer_payload_delivered_params() -> ProposerPayloadDeliveredParams {
         ProposerPayloadDeliveredParams {
             slot: Some(HEAD_SLOT),
             cursor: None,
+            from_slot: None,
+            to_slot: None,
             limit: None,
             block_hash: None,
             block_number: None,
@@ -178,6 +195,136 @@ mod data_api_tests {
         let _ = tx.send(());
     }
 
+    async fn send_payload_delivered_request(
+        http_config: &HttpServiceConfig,
+        query_params: &ProposerPayloadDeliveredParams,
+    ) -> Response {
+        let req_url = format!(
+            "{}{}{}",
+            http_config.base_url(),
+            PATH_DATA_API,
+            PATH_PROPOSER_PAYLOAD_DELIVERED,
+        );
+
+        Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(query_params)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_payload_delivered_filter_by_builder() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let mut query_params = get_test_proposer_payload_delivered_params();
+        query_params.slot = None;
+        query_params.builder_pubkey = Some(BlsPublicKey::default());
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let _response: Vec<DeliveredPayloadsResponse> = serde_json::from_str(&text).unwrap();
+
+        let filters = BidFilters::from(query_params);
+        assert_eq!(filters.builder_pubkey, Some(BlsPublicKey::default()));
+        assert!(filters.slot.is_none());
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_payload_delivered_filter_by_slot_range() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let mut query_params = get_test_proposer_payload_delivered_params();
+        query_params.slot = None;
+        query_params.from_slot = Some(HEAD_SLOT);
+        query_params.to_slot = Some(HEAD_SLOT + MAX_SLOT_RANGE - 1);
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let filters = BidFilters::from(query_params);
+        assert_eq!(filters.from_slot, Some(HEAD_SLOT));
+        assert_eq!(filters.to_slot, Some(HEAD_SLOT + MAX_SLOT_RANGE - 1));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_payload_delivered_slot_range_too_large() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let mut query_params = get_test_proposer_payload_delivered_params();
+        query_params.slot = None;
+        query_params.from_slot = Some(HEAD_SLOT);
+        query_params.to_slot = Some(HEAD_SLOT + MAX_SLOT_RANGE);
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), format!("maximum slot range is {MAX_SLOT_RANGE}"));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_payload_delivered_invalid_slot_range() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Missing upper bound
+        let mut query_params = get_test_proposer_payload_delivered_params();
+        query_params.slot = None;
+        query_params.from_slot = Some(HEAD_SLOT);
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // Lower bound above upper bound
+        query_params.from_slot = Some(HEAD_SLOT + 1);
+        query_params.to_slot = Some(HEAD_SLOT);
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            "from_slot and to_slot must both be set and from_slot must not exceed to_slot"
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_payload_delivered_limit_reached() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let mut query_params = get_test_proposer_payload_delivered_params();
+        query_params.limit = Some(501);
+
+        let resp = send_payload_delivered_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "maximum limit is 500");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
     #[tokio::test]
     #[serial]
     #[ignore]
@@ -300,4 +447,376 @@ mod data_api_tests {
         // Shut down the server
         let _ = tx.send(());
     }
+
+    async fn send_bids_received_request(
+        http_config: &HttpServiceConfig,
+        query_params: &BidsReceivedParams,
+    ) -> Response {
+        let req_url =
+            format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_BIDS_RECEIVED);
+
+        Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(query_params)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_bids_received_returns_all_bids_ordered_by_value() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let query_params = BidsReceivedParams { slot: HEAD_SLOT, cursor: None, limit: None };
+
+        let resp = send_bids_received_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let response: BidsReceivedResponse = serde_json::from_str(&text).unwrap();
+
+        let values = response.bids.iter().map(|bid| bid.value).collect::<Vec<_>>();
+        assert_eq!(values, vec![U256::from(3000), U256::from(2000), U256::from(1000)]);
+        assert!(response.bids.iter().all(|bid| bid.slot == HEAD_SLOT));
+        assert!(response.next_cursor.is_none());
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_bids_received_paginated() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let query_params = BidsReceivedParams { slot: HEAD_SLOT, cursor: None, limit: Some(2) };
+
+        let resp = send_bids_received_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let response: BidsReceivedResponse = serde_json::from_str(&text).unwrap();
+
+        let values = response.bids.iter().map(|bid| bid.value).collect::<Vec<_>>();
+        assert_eq!(values, vec![U256::from(3000), U256::from(2000)]);
+        assert_eq!(response.next_cursor, Some(response.bids[1].block_hash.clone()));
+        assert_eq!(response.next_cursor, Some(Hash32::try_from([1u8; 32].as_ref()).unwrap()));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_bids_received_limit_reached() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let query_params = BidsReceivedParams { slot: HEAD_SLOT, cursor: None, limit: Some(501) };
+
+        let resp = send_bids_received_request(&http_config, &query_params).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "maximum limit is 500");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_bids_received_compressed() {
+        // Start the server with compression enabled
+        let compression_config = ResponseCompressionConfig { min_size: 1_024 };
+        let (router, _api, _database) = data_api_app_with_layers(Some(compression_config), None);
+        let (tx, http_config) = serve(router).await;
+
+        let req_url = format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_BIDS_RECEIVED);
+        let query_params = BidsReceivedParams { slot: HEAD_SLOT, cursor: None, limit: None };
+
+        // Responses are not compressed unless the client supports it
+        let resp = send_bids_received_request(&http_config, &query_params).await;
+        assert!(resp.headers().get("content-encoding").is_none());
+        let uncompressed = resp.text().await.unwrap();
+        let response: BidsReceivedResponse = serde_json::from_str(&uncompressed).unwrap();
+        assert_eq!(response.bids.len(), 3);
+
+        // Gzip encoded response decodes to the full listing
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept-encoding", "gzip")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-encoding").unwrap(), "gzip");
+        let mut text = String::new();
+        GzDecoder::new(resp.bytes().await.unwrap().as_ref()).read_to_string(&mut text).unwrap();
+        assert_eq!(text, uncompressed);
+
+        // Brotli is used when preferred by the client
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept-encoding", "br")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-encoding").unwrap(), "br");
+        let mut text = String::new();
+        brotli::Decompressor::new(resp.bytes().await.unwrap().as_ref(), 4_096)
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, uncompressed);
+
+        // Responses below the minimum size are not compressed
+        let query_params = BidsReceivedParams { slot: HEAD_SLOT, cursor: None, limit: Some(1) };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept-encoding", "gzip")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert!(resp.headers().get("content-encoding").is_none());
+        let text = resp.text().await.unwrap();
+        let response: BidsReceivedResponse = serde_json::from_str(&text).unwrap();
+        assert_eq!(response.bids.len(), 1);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    async fn send_cors_request(
+        http_config: &HttpServiceConfig,
+        method: reqwest::Method,
+        origin: &str,
+    ) -> Response {
+        let req_url = format!(
+            "{}{}{}?slot={}",
+            http_config.base_url(),
+            PATH_DATA_API,
+            PATH_BIDS_RECEIVED,
+            HEAD_SLOT
+        );
+
+        Client::new()
+            .request(method, req_url.as_str())
+            .header("origin", origin)
+            .header("access-control-request-method", "GET")
+            .send()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_cors_allowed_and_disallowed_origins() {
+        // Start the server with a CORS policy
+        let cors_config = CorsConfig {
+            allowed_origins: vec![
+                "https://dashboard.example.org".to_string(),
+                "https://*.example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+        let (router, _api, _database) =
+            data_api_app_with_layers(None, Some(cors_layer(&cors_config).unwrap()));
+        let (tx, http_config) = serve(router).await;
+
+        // Exact and subdomain matches are allowed
+        for origin in ["https://dashboard.example.org", "https://a.b.example.com"] {
+            let resp = send_cors_request(&http_config, reqwest::Method::GET, origin).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(resp.headers().get("access-control-allow-origin").unwrap(), origin);
+        }
+
+        // Preflight requests are answered for allowed origins
+        let resp =
+            send_cors_request(&http_config, reqwest::Method::OPTIONS, "https://a.example.com").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get("access-control-allow-origin").unwrap(),
+            "https://a.example.com"
+        );
+        assert_eq!(resp.headers().get("access-control-allow-methods").unwrap(), "GET");
+
+        // Any other origin gets no CORS headers
+        for origin in ["https://example.com", "https://evilexample.com", "http://a.example.com"] {
+            let resp = send_cors_request(&http_config, reqwest::Method::GET, origin).await;
+            assert!(resp.headers().get("access-control-allow-origin").is_none());
+        }
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[test]
+    fn test_cors_layer_rejects_invalid_config() {
+        let cors_config =
+            CorsConfig { allowed_methods: vec!["GET POST".to_string()], ..Default::default() };
+        assert_eq!(cors_layer(&cors_config).unwrap_err(), "invalid CORS method: GET POST");
+
+        let cors_config = CorsConfig {
+            allowed_headers: vec!["content-type".to_string(), "x header".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(cors_layer(&cors_config).unwrap_err(), "invalid CORS header: x header");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_export_delivered_payloads_ndjson() {
+        // Start the server
+        let (tx, http_config, _api, database) = start_api_server().await;
+
+        // Seed payloads across three chunks, plus one past the end of the range
+        let to_slot = 2 * EXPORT_CHUNK_SLOTS + 500;
+        let seeded_slots =
+            [5, EXPORT_CHUNK_SLOTS - 1, EXPORT_CHUNK_SLOTS, to_slot - 100, to_slot, to_slot + 1];
+        database.set_delivered_payloads(
+            seeded_slots
+                .iter()
+                .map(|&slot| DeliveredPayloadDocument {
+                    bid_trace: BidTrace {
+                        slot,
+                        gas_limit: 30_000_000,
+                        gas_used: slot * 10,
+                        value: U256::from(slot * 1_000),
+                        ..Default::default()
+                    },
+                    block_number: slot + 100,
+                    num_txs: slot as usize % 50,
+                })
+                .collect(),
+        );
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::ExportDeliveredPayloads.path());
+        let query_params = ExportDeliveredPayloadsParams { from_slot: 0, to_slot };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/x-ndjson");
+
+        let text = resp.text().await.unwrap();
+        let records = text
+            .lines()
+            .map(|line| serde_json::from_str::<DeliveredPayloadsResponse>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        // Every seeded payload in range is exported exactly once, oldest first
+        assert_eq!(records.len(), 5);
+        assert_eq!(
+            records.iter().map(|record| record.slot).collect::<Vec<_>>(),
+            seeded_slots[..5].to_vec()
+        );
+
+        let record = &records[1];
+        assert_eq!(record.slot, EXPORT_CHUNK_SLOTS - 1);
+        assert_eq!(record.block_number, EXPORT_CHUNK_SLOTS + 99);
+        assert_eq!(record.num_tx, (EXPORT_CHUNK_SLOTS - 1) as usize % 50);
+        assert_eq!(record.gas_limit, 30_000_000);
+        assert_eq!(record.gas_used, (EXPORT_CHUNK_SLOTS - 1) * 10);
+        assert_eq!(record.value, U256::from((EXPORT_CHUNK_SLOTS - 1) * 1_000));
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_export_delivered_payloads_slot_range_too_large() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::ExportDeliveredPayloads.path());
+        let query_params =
+            ExportDeliveredPayloadsParams { from_slot: 0, to_slot: MAX_EXPORT_SLOT_RANGE };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            format!("maximum slot range is {MAX_EXPORT_SLOT_RANGE}")
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_export_delivered_payloads_slot_too_large() {
+        // Start the server
+        let (tx, http_config, _api, database) = start_api_server().await;
+        database.set_delivered_payloads(vec![DeliveredPayloadDocument {
+            bid_trace: BidTrace { slot: 5, ..Default::default() },
+            block_number: 105,
+            num_txs: 0,
+        }]);
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::ExportDeliveredPayloads.path());
+
+        // Slots past what the database can filter on are rejected
+        let query_params = ExportDeliveredPayloadsParams {
+            from_slot: u64::MAX - EXPORT_CHUNK_SLOTS / 2,
+            to_slot: u64::MAX,
+        };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), format!("maximum slot is {MAX_EXPORT_SLOT}"));
+
+        // A partial final chunk ending at the highest slot is exported, with nothing in range
+        let query_params = ExportDeliveredPayloadsParams {
+            from_slot: MAX_EXPORT_SLOT - EXPORT_CHUNK_SLOTS / 2,
+            to_slot: MAX_EXPORT_SLOT,
+        };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.text().await.unwrap(), "");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_export_delivered_payloads_invalid_slot_range() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        let req_url = format!("{}{}", http_config.base_url(), Route::ExportDeliveredPayloads.path());
+        let query_params = ExportDeliveredPayloadsParams { from_slot: 10, to_slot: 9 };
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
 }
@@ -1,48 +1,65 @@
 use axum::{
-    error_handling::HandleErrorLayer, http::StatusCode, middleware, routing::{get, post}, Extension, Router
+    error_handling::HandleErrorLayer, http::{HeaderName, HeaderValue, Method, StatusCode}, middleware, routing::{get, post, MethodRouter}, Extension, Router
 };
 use helix_beacon_client::{beacon_client::BeaconClient, multi_beacon_client::MultiBeaconClient};
-use helix_common::{Route, RouterConfig};
-use helix_database::postgres::postgres_db_service::PostgresDatabaseService;
-use helix_datastore::redis::redis_cache::RedisCache;
+use helix_common::{CorsConfig, ResponseCompressionConfig, Route, RouterConfig};
+use helix_database::{postgres::postgres_db_service::PostgresDatabaseService, DatabaseService};
+use helix_datastore::{redis::redis_cache::RedisCache, CircuitBreakerAuctioneer};
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tower::{timeout::TimeoutLayer, BoxError, ServiceBuilder};
-use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::{
+    compression::{predicate::SizeAbove, CompressionLayer},
+    cors::{AllowOrigin, CorsLayer},
+    limit::RequestBodyLimitLayer,
+};
 
 use crate::{
+    admin::AdminApi,
     builder::{
         api::{BuilderApi, MAX_PAYLOAD_LENGTH},
         optimistic_simulator::OptimisticSimulator,
-    }, gossiper::grpc_gossiper::GrpcGossiperClientManager, middleware::rate_limiting::rate_limit_by_ip::{rate_limit_by_ip, RateLimitState, RateLimitStateForRoute}, proposer::
+        reputation::BuilderReputationStore,
+        trace_recorder::TraceRecorder,
+    }, gossiper::grpc_gossiper::GrpcGossiperClientManager, middleware::{admin_auth::admin_auth::{require_admin_auth, AdminAuthState}, body_read_limit::body_read_limit::{limit_body_reads, BodyReadLimitState}, rate_limiting::rate_limit_by_ip::{rate_limit_by_ip, RateLimitState, RateLimitStateForRoute}, request_id::request_id::assign_request_id, route_timeout::route_timeout::{enforce_route_timeout, RouteTimeoutState}}, proposer::
         api::ProposerApi
     , relay_data::{
-        BidsCache, DataApi, DeliveredPayloadsCache, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API
+        BidsCache, DataApi, DeliveredPayloadsCache
     }, service::API_REQUEST_TIMEOUT
 };
 
+pub type AuctioneerProd = CircuitBreakerAuctioneer<RedisCache>;
+
 pub type BuilderApiProd = BuilderApi<
-    RedisCache,
+    AuctioneerProd,
     PostgresDatabaseService,
-    OptimisticSimulator<RedisCache, PostgresDatabaseService>,
+    OptimisticSimulator<AuctioneerProd, PostgresDatabaseService>,
     GrpcGossiperClientManager,
 >;
 
 pub type ProposerApiProd =
-    ProposerApi<RedisCache, PostgresDatabaseService, MultiBeaconClient<BeaconClient>, GrpcGossiperClientManager>;
+    ProposerApi<AuctioneerProd, PostgresDatabaseService, MultiBeaconClient<BeaconClient>, GrpcGossiperClientManager>;
 
 pub type DataApiProd = DataApi<PostgresDatabaseService>;
 
+pub type AdminApiProd =
+    AdminApi<PostgresDatabaseService, Arc<MultiBeaconClient<BeaconClient>>, Arc<AuctioneerProd>>;
+
+/// Returns an error if the router config is invalid, e.g. the CORS policy names an invalid method.
 pub fn build_router(
     router_config: &mut RouterConfig,
     builder_api: Arc<BuilderApiProd>,
     proposer_api: Arc<ProposerApiProd>,
     data_api: Arc<DataApiProd>,
+    admin_api: Arc<AdminApiProd>,
     bids_cache: Arc<BidsCache>,
     delivered_payloads_cache: Arc<DeliveredPayloadsCache>,
-) -> Router {
+    builder_reputation: Arc<BuilderReputationStore>,
+    trace_recorder: Arc<TraceRecorder>,
+) -> Result<Router, String> {
     router_config.resolve_condensed_routes();
 
     let mut rate_limits_per_route = HashMap::new();
+    let mut timeouts_per_route = HashMap::new();
     for route_info in &router_config.enabled_routes {
         if let Some(rate_limit) = route_info.rate_limit.as_ref() {
             rate_limits_per_route.insert(route_info.route.path(), RateLimitStateForRoute::new(
@@ -50,11 +67,28 @@ pub fn build_router(
                 rate_limit.max_requests,
             ));
         }
+        if let Some(timeout_ms) = route_info.timeout_ms {
+            timeouts_per_route.insert(route_info.route.path(), Duration::from_millis(timeout_ms));
+        }
     }
     let rate_limiting_state = RateLimitState::new(rate_limits_per_route);
     let mut router = Router::new().with_state(rate_limiting_state.clone());
 
+    let body_read_limit = router_config
+        .max_concurrent_body_reads
+        .map(|max_concurrent_reads| BodyReadLimitState::new(max_concurrent_reads, MAX_PAYLOAD_LENGTH));
+    let cors = router_config.cors.as_ref().map(cors_layer).transpose()?;
+
     for route in router_config.enabled_routes.iter().map(|route_info| route_info.route) {
+        if let Some(method_router) = data_api_route::<PostgresDatabaseService>(
+            route,
+            &router_config.data_api_compression,
+            &cors,
+        ) {
+            router = router.route(&route.path(), method_router);
+            continue;
+        }
+
         match route {
             Route::GetValidators => {
                 router = router.route(
@@ -66,21 +100,21 @@ pub fn build_router(
                 router = router
                     .route(
                         &route.path(),
-                        post(BuilderApiProd::submit_block),
+                        with_body_read_limit(post(BuilderApiProd::submit_block), &body_read_limit),
                     );
             }
             Route::SubmitBlockOptimistic => {
                 router = router
                     .route(
                         &route.path(),
-                        post(BuilderApiProd::submit_block_v2),
+                        with_body_read_limit(post(BuilderApiProd::submit_block_v2), &body_read_limit),
                     );
             }
             Route::SubmitHeader => {
                 router = router
                     .route(
                         &route.path(),
-                        post(BuilderApiProd::submit_header),
+                        with_body_read_limit(post(BuilderApiProd::submit_header), &body_read_limit),
                     );
             }
             Route::GetTopBid => {
@@ -116,22 +150,28 @@ pub fn build_router(
                         post(ProposerApiProd::get_payload),
                     );
             }
-            Route::ProposerPayloadDelivered => {
+            Route::SubscribeTopBid => {
                 router = router.route(
                     &route.path(),
-                    get(DataApiProd::proposer_payload_delivered),
+                    get(ProposerApiProd::subscribe_top_bid),
                 );
             }
-            Route::BuilderBidsReceived => {
+            Route::UpdateBuilderAccess => {
                 router = router.route(
-                    &format!("{PATH_DATA_API}{PATH_BUILDER_BIDS_RECEIVED}"),
-                    get(DataApiProd::builder_bids_received),
+                    &route.path(),
+                    post(BuilderApiProd::update_builder_access),
                 );
             }
-            Route::ValidatorRegistration => {
+            Route::UpdateMinBidValue => {
                 router = router.route(
                     &route.path(),
-                    get(DataApiProd::validator_registration),
+                    post(BuilderApiProd::update_min_bid_value),
+                );
+            }
+            Route::RefreshProposerDuties => {
+                router = router.route(
+                    &route.path(),
+                    post(AdminApiProd::refresh_proposer_duties),
                 );
             }
             _ => {
@@ -146,6 +186,10 @@ pub fn build_router(
     // Add Rate-Limiting Layer
     router = router.route_layer(middleware::from_fn_with_state(rate_limiting_state.clone(), rate_limit_by_ip));
 
+    // Abort requests running past their route's deadline
+    let route_timeout_state = RouteTimeoutState::new(timeouts_per_route);
+    router = router.route_layer(middleware::from_fn_with_state(route_timeout_state, enforce_route_timeout));
+
     // Add Timeout-Layer
     // Add Error-handling layer
     router = router.layer(
@@ -154,13 +198,123 @@ pub fn build_router(
             .layer(TimeoutLayer::new(API_REQUEST_TIMEOUT)),
     );
 
+    // Reject unauthenticated admin requests before any handler logic
+    let admin_auth_state = AdminAuthState::new(&router_config.admin_auth);
+    router = router.layer(middleware::from_fn_with_state(admin_auth_state, require_admin_auth));
+
+    // Assign request ids and echo them to the client
+    router = router.layer(middleware::from_fn(assign_request_id));
+
     // Add Extension layers
     router = router
         .layer(Extension(builder_api))
         .layer(Extension(proposer_api))
         .layer(Extension(data_api))
+        .layer(Extension(admin_api))
         .layer(Extension(bids_cache))
-        .layer(Extension(delivered_payloads_cache));
+        .layer(Extension(delivered_payloads_cache))
+        .layer(Extension(builder_reputation))
+        .layer(Extension(trace_recorder));
 
-    router
+    Ok(router)
+}
+
+/// Builds the handler of a data API route, with the configured response compression and CORS
+/// policy. Returns `None` for routes outside the data API.
+pub(crate) fn data_api_route<DB: DatabaseService + 'static>(
+    route: Route,
+    compression: &Option<ResponseCompressionConfig>,
+    cors: &Option<CorsLayer>,
+) -> Option<MethodRouter> {
+    let method_router = match route {
+        Route::ProposerPayloadDelivered => {
+            with_response_compression(get(DataApi::<DB>::proposer_payload_delivered), compression)
+        }
+        Route::BuilderBidsReceived => {
+            with_response_compression(get(DataApi::<DB>::builder_bids_received), compression)
+        }
+        Route::BidsReceived => {
+            with_response_compression(get(DataApi::<DB>::bids_received), compression)
+        }
+        Route::ValidatorRegistration => get(DataApi::<DB>::validator_registration),
+        Route::BuilderReputation => get(DataApi::<DB>::builder_reputation),
+        Route::ExportDeliveredPayloads => return Some(get(DataApi::<DB>::export_delivered_payloads)),
+        Route::RecordedTraces => return Some(get(DataApi::<DB>::recorded_traces)),
+        _ => return None,
+    };
+    Some(with_cors(method_router, cors))
+}
+
+/// Caps the number of concurrently buffered bodies for the route, if a limit is configured.
+fn with_body_read_limit(method_router: MethodRouter, body_read_limit: &Option<BodyReadLimitState>) -> MethodRouter {
+    match body_read_limit {
+        Some(state) => method_router.layer(middleware::from_fn_with_state(state.clone(), limit_body_reads)),
+        None => method_router,
+    }
+}
+
+/// Compresses the route's responses, if compression is configured.
+fn with_response_compression(method_router: MethodRouter, config: &Option<ResponseCompressionConfig>) -> MethodRouter {
+    match config {
+        Some(config) => method_router.layer(response_compression_layer(config)),
+        None => method_router,
+    }
+}
+
+/// Compresses responses of at least `min_size` bytes with gzip or brotli, based on the
+/// `Accept-Encoding` header. Bodies of unknown size are compressed as they are streamed.
+fn response_compression_layer(config: &ResponseCompressionConfig) -> CompressionLayer<SizeAbove> {
+    CompressionLayer::new().gzip(true).br(true).compress_when(SizeAbove::new(config.min_size))
+}
+
+/// Allows cross-origin requests to the route, if a CORS policy is configured.
+fn with_cors(method_router: MethodRouter, cors: &Option<CorsLayer>) -> MethodRouter {
+    match cors {
+        Some(cors) => method_router.layer(cors.clone()),
+        None => method_router,
+    }
+}
+
+/// Answers preflight requests and adds CORS headers to responses for the configured origins.
+/// Requests from any other origin get no CORS headers, so browsers block them.
+///
+/// Returns an error naming the first allowed method or header that is not valid.
+pub(crate) fn cors_layer(config: &CorsConfig) -> Result<CorsLayer, String> {
+    let allowed_origins = config.allowed_origins.clone();
+    let allowed_methods = config
+        .allowed_methods
+        .iter()
+        .map(|method| {
+            Method::from_bytes(method.as_bytes())
+                .map_err(|_| format!("invalid CORS method: {method}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let allowed_headers = config
+        .allowed_headers
+        .iter()
+        .map(|header| {
+            HeaderName::from_bytes(header.as_bytes())
+                .map_err(|_| format!("invalid CORS header: {header}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+            origin.to_str().is_ok_and(|origin| {
+                allowed_origins.iter().any(|allowed| is_allowed_origin(allowed, origin))
+            })
+        }))
+        .allow_methods(allowed_methods)
+        .allow_headers(allowed_headers))
+}
+
+/// Matches `origin` exactly, or as a subdomain if `allowed` is written as `https://*.example.com`.
+fn is_allowed_origin(allowed: &str, origin: &str) -> bool {
+    match allowed.split_once("*.") {
+        Some((scheme, domain)) => origin
+            .strip_prefix(scheme)
+            .and_then(|host| host.strip_suffix(domain))
+            .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+        None => allowed == origin,
+    }
 }
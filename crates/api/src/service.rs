@@ -9,18 +9,19 @@ use tokio::{
 use tracing::{error, info};
 
 use crate::{
-    builder::optimistic_simulator::OptimisticSimulator, gossiper::grpc_gossiper::GrpcGossiperClientManager, relay_data::{BidsCache, DeliveredPayloadsCache}, router::{build_router, BuilderApiProd, DataApiProd, ProposerApiProd}
+    builder::{access_policy::BuilderAccessPolicy, optimistic_simulator::OptimisticSimulator, reputation::BuilderReputationStore, simulation_queue::SimulationQueue, trace_recorder::TraceRecorder}, gossiper::grpc_gossiper::GrpcGossiperClientManager, proposer::payload_fallback::BuilderPayloadFallback, relay_data::{BidsCache, DeliveredPayloadsCache}, router::{build_router, AdminApiProd, AuctioneerProd, BuilderApiProd, DataApiProd, ProposerApiProd}
 };
 use helix_beacon_client::{
     beacon_client::BeaconClient, fiber_broadcaster::FiberBroadcaster,
     multi_beacon_client::MultiBeaconClient, BlockBroadcaster, MultiBeaconClientTrait,
 };
 use helix_common::{
-    chain_info::ChainInfo, signing::RelaySigningContext, BroadcasterConfig, NetworkConfig, RelayConfig
+    chain_info::ChainInfo, signing::RelaySigningContext, BroadcasterConfig, NetworkConfig, RelayConfig, SignerConfig
 };
 use helix_database::{postgres::postgres_db_service::PostgresDatabaseService, DatabaseService};
 use helix_datastore::redis::redis_cache::RedisCache;
 use helix_housekeeper::{ChainEventUpdater, Housekeeper};
+use helix_utils::signer::{LocalSigner, RelaySigner, RemoteSigner};
 
 pub(crate) const API_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 pub(crate) const SIMULATOR_REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
@@ -32,7 +33,9 @@ const PAYLOAD_ATTRIBUTE_CHANNEL_SIZE: usize = 300;
 pub struct ApiService {}
 
 impl ApiService {
-    pub async fn run(mut config: RelayConfig) {
+    /// Runs the relay until the server exits. Returns an error, without serving any request, if
+    /// the router config is invalid.
+    pub async fn run(mut config: RelayConfig) -> Result<(), String> {
         let postgres_db = PostgresDatabaseService::from_relay_config(&config).unwrap();
         postgres_db.run_migrations().await;
         postgres_db.init_region(&config).await;
@@ -43,12 +46,15 @@ impl ApiService {
 
         let builder_infos = db.get_all_builder_infos().await.expect("failed to load builder infos");
         
-        let auctioneer = Arc::new(RedisCache::new(&config.redis.url, builder_infos).await.unwrap());
+        let redis_cache = Arc::new(RedisCache::new(&config.redis.url, builder_infos).await.unwrap());
+        let auctioneer = Arc::new(AuctioneerProd::new(
+            redis_cache.clone(),
+            &config.auctioneer_circuit_breaker,
+        ));
 
-        let auctioneer_clone = auctioneer.clone();
         tokio::spawn(async move {
             loop {
-                if let Err(err) = auctioneer_clone.start_best_bid_listener().await {
+                if let Err(err) = redis_cache.start_best_bid_listener().await {
                     tracing::error!("Bid listener error: {}", err);
                     sleep(Duration::from_secs(5)).await;
                 }
@@ -89,6 +95,7 @@ impl ApiService {
         let housekeeper =
             Housekeeper::new(db.clone(), multi_beacon_client.clone(), auctioneer.clone(), config.clone());
         let mut housekeeper_head_events = head_event_receiver.resubscribe();
+        let admin_housekeeper = housekeeper.clone();
         tokio::spawn(async move {
             loop {
                 if let Err(err) = housekeeper.start(&mut housekeeper_head_events).await {
@@ -99,29 +106,39 @@ impl ApiService {
         });
 
         // Initialise relay signing context
-        let signing_key_str = env::var("RELAY_KEY").expect("could not find RELAY_KEY in env");
-        let signing_key = SecretKey::try_from(signing_key_str)
-            .expect("could not convert env signing key to SecretKey");
-        let public_key = signing_key.public_key();
-        info!(relay_pub_key = ?public_key);
-        let relay_signing_context = Arc::new(RelaySigningContext {
-            signing_key,
-            public_key,
-            context: chain_info.context.clone(),
-        });
+        let signer: Arc<dyn RelaySigner> = match &config.signer {
+            SignerConfig::Local => {
+                let signing_key_str =
+                    env::var("RELAY_KEY").expect("could not find RELAY_KEY in env");
+                let signing_key = SecretKey::try_from(signing_key_str)
+                    .expect("could not convert env signing key to SecretKey");
+                Arc::new(LocalSigner::new(signing_key))
+            }
+            SignerConfig::Remote(remote_config) => Arc::new(RemoteSigner::new(
+                remote_config.url.clone(),
+                remote_config.public_key.clone(),
+                Duration::from_millis(remote_config.timeout_ms),
+            )),
+        };
+        info!(relay_pub_key = ?signer.public_key());
+        let relay_signing_context =
+            Arc::new(RelaySigningContext::new(signer, chain_info.context.clone()));
 
         let client =
             reqwest::ClientBuilder::new().timeout(SIMULATOR_REQUEST_TIMEOUT).build().unwrap();
 
-        let simulator = OptimisticSimulator::<RedisCache, PostgresDatabaseService>::new(
+        let simulator = OptimisticSimulator::<AuctioneerProd, PostgresDatabaseService>::new(
             auctioneer.clone(),
             db.clone(),
             client,
             config.simulator.url,
+            config.simulator.queue.as_ref().map(|queue| Arc::new(SimulationQueue::new(queue))),
         );
 
         let (mut chain_event_updater, slot_update_sender) =
-            ChainEventUpdater::new(db.clone(), chain_info.clone());
+            ChainEventUpdater::new(db.clone(), multi_beacon_client.clone(), chain_info.clone());
+        let admin_api =
+            Arc::new(AdminApiProd::new(admin_housekeeper, chain_event_updater.duties_refresh_sender()));
 
         let chain_updater_head_events = head_event_receiver.resubscribe();
         let chain_updater_payload_events = payload_attribute_receiver.resubscribe();
@@ -139,6 +156,10 @@ impl ApiService {
             .expect("failed to initialise gRPC gossiper"),
         );
 
+        let builder_reputation =
+            Arc::new(BuilderReputationStore::new(config.builder_reputation.clone()));
+        let trace_recorder = Arc::new(TraceRecorder::new(&config.trace_recorder));
+
         let (builder_gossip_sender, builder_gossip_receiver) = tokio::sync::mpsc::channel(10_000);
         let (proposer_gossip_sender, proposer_gossip_receiver) = tokio::sync::mpsc::channel(10_000);
 
@@ -149,6 +170,10 @@ impl ApiService {
             simulator,
             gossiper.clone(),
             relay_signing_context,
+            builder_reputation.clone(),
+            Arc::new(BuilderAccessPolicy::new(config.builder_access.clone())),
+            trace_recorder.clone(),
+            config.min_bid_value,
             slot_update_sender.clone(),
             builder_gossip_receiver,
         ));
@@ -167,6 +192,12 @@ impl ApiService {
             slot_update_sender,
             validator_preferences.clone(),
             config.target_get_payload_propagation_duration_ms,
+            config.get_header_request_cutoff_ms,
+            config.clock_skew_tolerance_ms,
+            config
+                .builder_payload_fallback
+                .as_ref()
+                .map(|fallback_config| Arc::new(BuilderPayloadFallback::new(fallback_config))),
             proposer_gossip_receiver,
         ));
 
@@ -186,13 +217,25 @@ impl ApiService {
                 .build()
         );
 
-        let router = build_router(&mut config.router_config, builder_api, proposer_api, data_api, bids_cache, delivered_payloads_cache);
+        let router = build_router(
+            &mut config.router_config,
+            builder_api,
+            proposer_api,
+            data_api,
+            admin_api,
+            bids_cache,
+            delivered_payloads_cache,
+            builder_reputation,
+            trace_recorder,
+        )
+        .map_err(|err| format!("invalid router config: {err}"))?;
 
         let listener = tokio::net::TcpListener::bind("0.0.0.0:4040").await.unwrap();
         match axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>()).await {
             Ok(_) => info!("Server exited successfully"),
             Err(e) => error!("Server exited with error: {e}"),
         }
+        Ok(())
     }
 }
 
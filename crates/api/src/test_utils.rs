@@ -6,7 +6,8 @@ use axum::{
     routing::{get, post},
     BoxError, Extension, Router,
 };
-use helix_common::{chain_info::ChainInfo, Route};
+use ethereum_consensus::primitives::U256;
+use helix_common::{chain_info::ChainInfo, ResponseCompressionConfig, Route, TraceRecorderConfig};
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 
 use helix_beacon_client::{
@@ -18,23 +19,27 @@ use helix_database::MockDatabaseService;
 use helix_datastore::MockAuctioneer;
 use helix_housekeeper::ChainUpdate;
 use tower::{buffer::BufferLayer, limit::RateLimitLayer, timeout::TimeoutLayer, ServiceBuilder};
-use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::{cors::CorsLayer, limit::RequestBodyLimitLayer};
 
 use crate::{
     builder::{
+        access_policy::BuilderAccessPolicy,
         api::{BuilderApi, MAX_PAYLOAD_LENGTH},
         mock_simulator::MockSimulator,
+        reputation::BuilderReputationStore,
+        trace_recorder::TraceRecorder,
     },
     gossiper::{mock_gossiper::MockGossiper, types::GossipedMessage},
     proposer::{
         api::{ProposerApi, MAX_BLINDED_BLOCK_LENGTH, MAX_VAL_REGISTRATIONS_LENGTH},
         PATH_GET_HEADER, PATH_GET_PAYLOAD, PATH_PROPOSER_API, PATH_REGISTER_VALIDATORS,
-        PATH_STATUS,
+        PATH_STATUS, PATH_SUBSCRIBE_TOP_BID,
     },
     relay_data::{
-        DataApi, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API, PATH_PROPOSER_PAYLOAD_DELIVERED,
-        PATH_VALIDATOR_REGISTRATION,
+        BidsCache, DataApi, DeliveredPayloadsCache, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API,
+        PATH_PROPOSER_PAYLOAD_DELIVERED, PATH_VALIDATOR_REGISTRATION,
     },
+    router::data_api_route,
 };
 
 pub fn app() -> Router {
@@ -52,6 +57,9 @@ pub fn app() -> Router {
             slot_update_sender,
             Arc::new(ValidatorPreferences::default()),
             0,
+            None,
+            0,
+            None,
             gossip_receiver,
         ));
 
@@ -119,19 +127,35 @@ pub fn builder_api_app() -> (
     Router,
     Arc<BuilderApi<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>>,
     Receiver<Sender<ChainUpdate>>,
+) {
+    builder_api_app_with_auctioneer(MockAuctioneer::default())
+}
+
+pub fn builder_api_app_with_auctioneer(
+    auctioneer: MockAuctioneer,
+) -> (
+    Router,
+    Arc<BuilderApi<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>>,
+    Receiver<Sender<ChainUpdate>>,
 ) {
     let (slot_update_sender, slot_update_receiver) = channel::<Sender<ChainUpdate>>(32);
     let (_gossip_sender, gossip_receiver) = tokio::sync::mpsc::channel(10);
+    let trace_recorder =
+        Arc::new(TraceRecorder::new(&TraceRecorderConfig { enabled: true, capacity: 100 }));
 
     let builder_api_service =
         Arc::new(
             BuilderApi::<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>::new(
-                Arc::new(MockAuctioneer::default()),
+                Arc::new(auctioneer),
                 Arc::new(MockDatabaseService::default()),
                 Arc::new(ChainInfo::for_mainnet()),
                 MockSimulator::default(),
                 Arc::new(MockGossiper::new().unwrap()),
                 Arc::new(RelaySigningContext::default()),
+                Arc::new(BuilderReputationStore::new(Default::default())),
+                Arc::new(BuilderAccessPolicy::default()),
+                trace_recorder.clone(),
+                U256::ZERO,
                 slot_update_sender.clone(),
                 gossip_receiver,
             ),
@@ -150,8 +174,21 @@ pub fn builder_api_app() -> (
             &Route::GetTopBid.path(),
             get(BuilderApi::<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>::get_top_bid),
         )
+        .route(
+            &Route::UpdateBuilderAccess.path(),
+            post(BuilderApi::<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>::update_builder_access),
+        )
+        .route(
+            &Route::UpdateMinBidValue.path(),
+            post(BuilderApi::<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>::update_min_bid_value),
+        )
+        .route(
+            &Route::RecordedTraces.path(),
+            get(DataApi::<MockDatabaseService>::recorded_traces),
+        )
         .layer(RequestBodyLimitLayer::new(MAX_PAYLOAD_LENGTH))
-        .layer(Extension(builder_api_service.clone()));
+        .layer(Extension(builder_api_service.clone()))
+        .layer(Extension(trace_recorder));
 
     // Add Timeout-Layer
     // Add Rate-Limit-Layer (buffered so we can clone the service)
@@ -190,6 +227,9 @@ pub fn proposer_api_app() -> (
             slot_update_sender.clone(),
             Arc::new(ValidatorPreferences::default()),
             0,
+            None,
+            0,
+            None,
             gossip_receiver,
         ));
 
@@ -198,6 +238,10 @@ pub fn proposer_api_app() -> (
             &format!("{PATH_PROPOSER_API}{PATH_GET_HEADER}"),
             get(ProposerApi::<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper>::get_header),
         )
+        .route(
+            &format!("{PATH_PROPOSER_API}{PATH_SUBSCRIBE_TOP_BID}"),
+            get(ProposerApi::<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper>::subscribe_top_bid),
+        )
         .route(
             &format!("{PATH_PROPOSER_API}{PATH_GET_PAYLOAD}"),
             post(ProposerApi::<MockAuctioneer, MockDatabaseService, MockMultiBeaconClient, MockGossiper>::get_payload),
@@ -214,23 +258,39 @@ pub fn proposer_api_app() -> (
 }
 
 pub fn data_api_app() -> (Router, Arc<DataApi<MockDatabaseService>>, Arc<MockDatabaseService>) {
+    data_api_app_with_layers(None, None)
+}
+
+/// Serves every data API route the way `build_router` does, with the given response compression
+/// and CORS policy.
+pub fn data_api_app_with_layers(
+    compression: Option<ResponseCompressionConfig>,
+    cors: Option<CorsLayer>,
+) -> (Router, Arc<DataApi<MockDatabaseService>>, Arc<MockDatabaseService>) {
     let mock_database = Arc::new(MockDatabaseService::default());
-    let proposer_api_service = Arc::new(DataApi::<MockDatabaseService>::new(Arc::new(ValidatorPreferences::default()), mock_database.clone()));
+    let data_api_service = Arc::new(DataApi::<MockDatabaseService>::new(Arc::new(ValidatorPreferences::default()), mock_database.clone()));
 
-    let router = Router::new()
-        .route(
-            &format!("{PATH_DATA_API}{PATH_PROPOSER_PAYLOAD_DELIVERED}"),
-            get(DataApi::<MockDatabaseService>::proposer_payload_delivered),
-        )
-        .route(
-            &format!("{PATH_DATA_API}{PATH_BUILDER_BIDS_RECEIVED}"),
-            get(DataApi::<MockDatabaseService>::builder_bids_received),
-        )
-        .route(
-            &format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION}"),
-            get(DataApi::<MockDatabaseService>::validator_registration),
-        )
-        .layer(Extension(proposer_api_service.clone()));
+    let mut router = Router::new();
+    for route in [
+        Route::ProposerPayloadDelivered,
+        Route::BuilderBidsReceived,
+        Route::ValidatorRegistration,
+        Route::BuilderReputation,
+        Route::BidsReceived,
+        Route::ExportDeliveredPayloads,
+        Route::RecordedTraces,
+    ] {
+        let method_router =
+            data_api_route::<MockDatabaseService>(route, &compression, &cors).unwrap();
+        router = router.route(&route.path(), method_router);
+    }
+
+    let router = router
+        .layer(Extension(data_api_service.clone()))
+        .layer(Extension(Arc::new(BidsCache::new(100))))
+        .layer(Extension(Arc::new(DeliveredPayloadsCache::new(100))))
+        .layer(Extension(Arc::new(BuilderReputationStore::new(Default::default()))))
+        .layer(Extension(Arc::new(TraceRecorder::new(&TraceRecorderConfig::default()))));
 
-    (router, proposer_api_service, mock_database)
+    (router, data_api_service, mock_database)
 }
@@ -185,6 +185,23 @@ impl BeaconClientTrait for BeaconClient {
         Ok((dependent_root, result.data))
     }
 
+    /// Fetches the block via <https://ethereum.github.io/beacon-APIs/#/Beacon/getBlockV2> and reads
+    /// the gas limit of its execution payload.
+    async fn get_block_gas_limit(&self, block_id: &str) -> Result<u64, BeaconClientError> {
+        let endpoint = format!("eth/v2/beacon/blocks/{block_id}");
+        let result: BeaconResponse<serde_json::Value> = self.get(&endpoint).await?;
+        result
+            .data
+            .pointer("/message/body/execution_payload/gas_limit")
+            .and_then(|gas_limit| gas_limit.as_str())
+            .and_then(|gas_limit| gas_limit.parse().ok())
+            .ok_or_else(|| {
+                BeaconClientError::MissingExpectedData(
+                    "missing `execution_payload.gas_limit` in block".to_string(),
+                )
+            })
+    }
+
     /// `publish_block` publishes the signed beacon block ssz-encoded via
     /// <https://ethereum.github.io/beacon-APIs/#/ValidatorRequiredApi/publishBlockV2>
     async fn publish_block<SB: Send + Sync + SimpleSerialize>(
@@ -289,6 +306,21 @@ mod beacon_client_tests {
         assert_eq!(proposer_duties.len(), 32);
     }
 
+    #[tokio::test]
+    async fn test_get_block_gas_limit_ok() {
+        let mut server = mockito::Server::new();
+        let _m = server.mock("GET", Matcher::Regex("/eth/v2/beacon/blocks/head".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"version":"deneb","execution_optimistic":false,"finalized":false,"data":{"message":{"slot":"7223680","body":{"execution_payload":{"block_number":"19000000","gas_limit":"30000000","gas_used":"12000000"}}},"signature":"0x00"}}"#)
+            .create();
+
+        let client = BeaconClient::from_endpoint_str(&server.url());
+        let result = client.get_block_gas_limit("head").await;
+
+        assert_eq!(result.unwrap(), 30_000_000);
+    }
+
     #[tokio::test]
     async fn test_publish_block_ok() {
         let mut server = mockito::Server::new();
@@ -17,6 +17,8 @@ pub struct MockBeaconClient {
     sync_status: SyncStatus,
     state_validators: Vec<ValidatorSummary>,
     proposer_duties: (Root, Vec<ProposerDuty>),
+    proposer_duties_unavailable: bool,
+    block_gas_limit: u64,
     publish_block_response_code: u16,
 }
 
@@ -26,6 +28,8 @@ impl MockBeaconClient {
             sync_status: SyncStatus { head_slot: 10, sync_distance: 0, is_syncing: false },
             state_validators: Vec::new(),
             proposer_duties: (Root::default(), Vec::new()),
+            proposer_duties_unavailable: false,
+            block_gas_limit: 30_000_000,
             publish_block_response_code: 200,
         }
     }
@@ -45,6 +49,16 @@ impl MockBeaconClient {
         self
     }
 
+    pub fn with_proposer_duties_unavailable(mut self) -> Self {
+        self.proposer_duties_unavailable = true;
+        self
+    }
+
+    pub fn with_block_gas_limit(mut self, block_gas_limit: u64) -> Self {
+        self.block_gas_limit = block_gas_limit;
+        self
+    }
+
     pub fn with_publish_block_response_code(mut self, publish_block_response_code: u16) -> Self {
         self.publish_block_response_code = publish_block_response_code;
         self
@@ -86,9 +100,16 @@ impl BeaconClientTrait for MockBeaconClient {
         &self,
         _epoch: u64,
     ) -> Result<(Root, Vec<ProposerDuty>), BeaconClientError> {
+        if self.proposer_duties_unavailable {
+            return Err(BeaconClientError::BeaconNodeUnavailable);
+        }
         Ok(self.proposer_duties.clone())
     }
 
+    async fn get_block_gas_limit(&self, _block_id: &str) -> Result<u64, BeaconClientError> {
+        Ok(self.block_gas_limit)
+    }
+
     fn get_uri(&self) -> String {
         "test_uri".to_string()
     }
@@ -1,6 +1,9 @@
-use std::sync::{
-    atomic::{AtomicBool, AtomicUsize},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize},
+        Arc,
+    },
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -24,6 +27,10 @@ pub struct MockMultiBeaconClient {
     _chan: Option<Sender<HeadEventData>>,
     state_validators_has_been_read: Arc<AtomicBool>,
     proposer_duties_has_been_read: Arc<AtomicBool>,
+    /// Number of `get_block_gas_limit` calls
+    pub block_gas_limit_calls: Arc<AtomicUsize>,
+    /// Delays `get_block_gas_limit` responses when set
+    pub block_gas_limit_delay: Option<Duration>,
 }
 
 impl MockMultiBeaconClient {
@@ -39,6 +46,8 @@ impl MockMultiBeaconClient {
             _chan: None,
             state_validators_has_been_read,
             proposer_duties_has_been_read,
+            block_gas_limit_calls: Arc::new(AtomicUsize::new(0)),
+            block_gas_limit_delay: None,
         }
     }
 }
@@ -97,6 +106,20 @@ impl MultiBeaconClientTrait for MockMultiBeaconClient {
             }],
         ))
     }
+    async fn get_proposer_duties_with_source(
+        &self,
+        epoch: u64,
+    ) -> Result<(String, Root, Vec<ProposerDuty>), BeaconClientError> {
+        let (root, proposer_duties) = self.get_proposer_duties(epoch).await?;
+        Ok(("test_uri".to_string(), root, proposer_duties))
+    }
+    async fn get_block_gas_limit(&self, _block_id: &str) -> Result<u64, BeaconClientError> {
+        self.block_gas_limit_calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if let Some(delay) = self.block_gas_limit_delay {
+            tokio::time::sleep(delay).await;
+        }
+        Ok(30_000_000)
+    }
     async fn publish_block<VersionedSignedProposal: SimpleSerialize + Send + Sync + 'static>(
         &self,
         _block: Arc<VersionedSignedProposal>,
@@ -11,7 +11,7 @@ use helix_common::{
     ValidatorSummary,
 };
 use tokio::{sync::broadcast::Sender, task::JoinError};
-use tracing::{error, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::{
     error::BeaconClientError,
@@ -166,14 +166,66 @@ impl<BeaconClient: BeaconClientTrait> MultiBeaconClientTrait for MultiBeaconClie
         &self,
         epoch: u64,
     ) -> Result<(Root, Vec<ProposerDuty>), BeaconClientError> {
+        let (_, root, proposer_duties) = self.get_proposer_duties_with_source(epoch).await?;
+        Ok((root, proposer_duties))
+    }
+
+    /// Fetches the proposer duties for `epoch`, starting with the beacon client that last
+    /// responded successfully.
+    ///
+    /// The other clients are tried as fallbacks, see `beacon_clients_by_last_response`. A fallback
+    /// that succeeds is tried first from then on, until it fails, even after the primary client
+    /// recovers. An error is only returned if every client fails.
+    async fn get_proposer_duties_with_source(
+        &self,
+        epoch: u64,
+    ) -> Result<(String, Root, Vec<ProposerDuty>), BeaconClientError> {
         let clients = self.beacon_clients_by_last_response();
         let mut last_error = None;
 
         for (i, client) in clients.into_iter() {
             match client.get_proposer_duties(epoch).await {
-                Ok(proposer_duties) => {
+                Ok((root, proposer_duties)) => {
+                    if i != self.best_beacon_instance.load(Ordering::Relaxed) {
+                        info!(
+                            epoch = epoch,
+                            beacon_client = %client.get_uri(),
+                            "switched beacon client for proposer duties",
+                        );
+                    }
+                    debug!(
+                        epoch = epoch,
+                        beacon_client = %client.get_uri(),
+                        "fetched proposer duties",
+                    );
                     self.best_beacon_instance.store(i, Ordering::Relaxed);
-                    return Ok(proposer_duties);
+                    return Ok((client.get_uri(), root, proposer_duties));
+                }
+                Err(err) => {
+                    warn!(
+                        epoch = epoch,
+                        beacon_client = %client.get_uri(),
+                        err = %err,
+                        "failed to fetch proposer duties",
+                    );
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        error!(epoch = epoch, "failed to fetch proposer duties from all beacon clients");
+        Err(last_error.unwrap_or(BeaconClientError::BeaconNodeUnavailable))
+    }
+
+    async fn get_block_gas_limit(&self, block_id: &str) -> Result<u64, BeaconClientError> {
+        let clients = self.beacon_clients_by_last_response();
+        let mut last_error = None;
+
+        for (i, client) in clients.into_iter() {
+            match client.get_block_gas_limit(block_id).await {
+                Ok(gas_limit) => {
+                    self.best_beacon_instance.store(i, Ordering::Relaxed);
+                    return Ok(gas_limit);
                 }
                 Err(err) => {
                     last_error = Some(err);
@@ -317,4 +369,35 @@ mod multi_beacon_client_tests {
 
         assert!(matches!(result, Err(BeaconClientError::BlockIntegrationFailed)));
     }
+
+    #[tokio::test]
+    async fn test_get_proposer_duties_falls_back_to_secondary() {
+        let duties = vec![ProposerDuty {
+            public_key: Default::default(),
+            validator_index: 1,
+            slot: 19,
+        }];
+        let client1 = Arc::new(MockBeaconClient::new().with_proposer_duties_unavailable());
+        let client2 = Arc::new(
+            MockBeaconClient::new().with_proposer_duties((Root::default(), duties)),
+        );
+
+        let multi_client = MultiBeaconClient::new(vec![client1, client2]);
+        let (_, proposer_duties) = multi_client.get_proposer_duties(0).await.unwrap();
+
+        assert_eq!(proposer_duties.len(), 1);
+        assert_eq!(proposer_duties[0].slot, 19);
+        assert_eq!(multi_client.best_beacon_instance.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_proposer_duties_all_unavailable() {
+        let client1 = Arc::new(MockBeaconClient::new().with_proposer_duties_unavailable());
+        let client2 = Arc::new(MockBeaconClient::new().with_proposer_duties_unavailable());
+
+        let multi_client = MultiBeaconClient::new(vec![client1, client2]);
+        let result = multi_client.get_proposer_duties(0).await;
+
+        assert!(matches!(result, Err(BeaconClientError::BeaconNodeUnavailable)));
+    }
 }
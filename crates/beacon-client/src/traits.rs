@@ -34,6 +34,7 @@ pub trait BeaconClientTrait: Send + Sync + Clone {
         &self,
         epoch: u64,
     ) -> Result<(Root, Vec<ProposerDuty>), BeaconClientError>;
+    async fn get_block_gas_limit(&self, block_id: &str) -> Result<u64, BeaconClientError>;
     async fn publish_block<VersionedSignedProposal: Send + Sync + SimpleSerialize>(
         &self,
         block: Arc<VersionedSignedProposal>,
@@ -57,6 +58,13 @@ pub trait MultiBeaconClientTrait: Send + Sync + Clone {
         &self,
         epoch: u64,
     ) -> Result<(Root, Vec<ProposerDuty>), BeaconClientError>;
+    /// Also returns the uri of the beacon client that served the duties.
+    async fn get_proposer_duties_with_source(
+        &self,
+        epoch: u64,
+    ) -> Result<(String, Root, Vec<ProposerDuty>), BeaconClientError>;
+    /// Gas limit of the execution payload in the block with `block_id`, e.g. a block root.
+    async fn get_block_gas_limit(&self, block_id: &str) -> Result<u64, BeaconClientError>;
     async fn publish_block<
         VersionedSignedProposal: Serialize + DeserializeOwned + Send + Sync + 'static + SimpleSerialize,
     >(
@@ -31,7 +31,10 @@ async fn run() {
         }
     }
 
-    ApiService::run(config).await;
+    if let Err(e) = ApiService::run(config).await {
+        println!("Failed to start relay: {}", e);
+        std::process::exit(1);
+    }
 }
 
 fn main() {
@@ -16,6 +16,13 @@ pub struct BuilderGetValidatorsResponseEntry {
     pub entry: ValidatorRegistrationInfo,
 }
 
+/// Admin request replacing the minimum value a submission has to declare.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct MinBidValueUpdate {
+    #[serde(with = "as_str")]
+    pub min_bid_value: U256,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
 pub struct BuilderGetValidatorsResponse {
     #[serde(with = "as_str")]
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub struct BidFilters {
     pub slot: Option<u64>,
     pub cursor: Option<u64>,
+    pub from_slot: Option<u64>,
+    pub to_slot: Option<u64>,
     pub limit: Option<u64>,
     pub block_hash: Option<Hash32>,
     pub block_number: Option<u64>,
@@ -17,6 +19,10 @@ pub struct BidFilters {
 pub struct ProposerPayloadDeliveredParams {
     pub slot: Option<u64>,
     pub cursor: Option<u64>,
+    /// First slot of the queried range, inclusive.
+    pub from_slot: Option<u64>,
+    /// Last slot of the queried range, inclusive.
+    pub to_slot: Option<u64>,
     pub limit: Option<u64>,
     pub block_hash: Option<Hash32>,
     pub block_number: Option<u64>,
@@ -30,6 +36,8 @@ impl From<ProposerPayloadDeliveredParams> for BidFilters {
         BidFilters {
             slot: value.slot,
             cursor: value.cursor,
+            from_slot: value.from_slot,
+            to_slot: value.to_slot,
             limit: value.limit,
             block_hash: value.block_hash,
             block_number: value.block_number,
@@ -82,6 +90,8 @@ impl From<BuilderBlocksReceivedParams> for BidFilters {
         BidFilters {
             slot: value.slot,
             cursor: None,
+            from_slot: None,
+            to_slot: None,
             limit: value.limit,
             block_hash: value.block_hash,
             block_number: value.block_number,
@@ -117,7 +127,59 @@ pub struct ReceivedBlocksResponse {
     pub timestamp_ms: u64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExportDeliveredPayloadsParams {
+    /// First slot of the exported range, inclusive.
+    pub from_slot: u64,
+    /// Last slot of the exported range, inclusive.
+    pub to_slot: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BidsReceivedParams {
+    pub slot: u64,
+    /// Block hash of the last bid on the previous page.
+    pub cursor: Option<Hash32>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BidsReceivedResponse {
+    pub bids: Vec<ReceivedBlocksResponse>,
+    /// Cursor for the next page, `None` once all bids for the slot have been returned.
+    pub next_cursor: Option<Hash32>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ValidatorRegistrationParams {
     pub pubkey: BlsPublicKey,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BuilderReputationParams {
+    pub pubkey: BlsPublicKey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuilderReputationState {
+    Good,
+    Throttled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuilderReputationResponse {
+    pub builder_pubkey: BlsPublicKey,
+    pub state: BuilderReputationState,
+    #[serde(with = "as_str")]
+    pub valid_submissions: usize,
+    #[serde(with = "as_str")]
+    pub invalid_submissions: usize,
+    #[serde(with = "as_str")]
+    pub avg_simulation_latency_ms: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RecordedTracesParams {
+    pub limit: Option<usize>,
+}
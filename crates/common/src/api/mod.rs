@@ -16,10 +16,26 @@ pub(crate) const PATH_STATUS: &str = "/status";
 pub(crate) const PATH_REGISTER_VALIDATORS: &str = "/validators";
 pub(crate) const PATH_GET_HEADER: &str = "/header/:slot/:parent_hash/:pubkey";
 pub(crate) const PATH_GET_PAYLOAD: &str = "/blinded_blocks";
+pub(crate) const PATH_SUBSCRIBE_TOP_BID: &str = "/top_bid/:slot/:parent_hash/:pubkey";
 
 
 pub(crate) const PATH_DATA_API: &str = "/relay/v1/data";
 
 pub(crate) const PATH_PROPOSER_PAYLOAD_DELIVERED: &str = "/bidtraces/proposer_payload_delivered";
 pub(crate) const PATH_BUILDER_BIDS_RECEIVED: &str = "/bidtraces/builder_blocks_received";
-pub(crate) const PATH_VALIDATOR_REGISTRATION: &str = "/validator_registration";
\ No newline at end of file
+pub(crate) const PATH_VALIDATOR_REGISTRATION: &str = "/validator_registration";
+pub(crate) const PATH_BUILDER_REPUTATION: &str = "/builder_reputation";
+pub(crate) const PATH_BIDS_RECEIVED: &str = "/bids_received";
+
+pub(crate) const PATH_ADMIN_API: &str = "/relay/v1/admin";
+
+pub(crate) const PATH_BUILDER_ACCESS: &str = "/builder_access";
+pub(crate) const PATH_MIN_BID_VALUE: &str = "/min_bid_value";
+pub(crate) const PATH_TRACES: &str = "/traces";
+pub(crate) const PATH_EXPORT_DELIVERED_PAYLOADS: &str = "/export/delivered_payloads";
+pub(crate) const PATH_REFRESH_PROPOSER_DUTIES: &str = "/proposer_duties/refresh";
+
+/// Whether `path` is served by the admin API.
+pub fn is_admin_path(path: &str) -> bool {
+    path.strip_prefix(PATH_ADMIN_API).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
@@ -133,6 +133,28 @@ impl ChainInfo {
         }
     }
 
+    /// Default cutoff for `get_header` requests, set to the first quarter of the slot.
+    pub fn get_header_request_cutoff_ms(&self) -> u64 {
+        self.seconds_per_slot * 1000 / 4
+    }
+
+    /// Upper bound for the configured clock skew tolerance, set to a twelfth of the slot.
+    pub fn max_clock_skew_tolerance_ms(&self) -> u64 {
+        self.seconds_per_slot * 1000 / 12
+    }
+
+    /// Milliseconds between the start of `slot` and `timestamp_ns`.
+    ///
+    /// Negative when the timestamp is before the slot start, e.g. for bids received during the
+    /// previous slot or before genesis. Saturates for slots too far from `timestamp_ns` to fit.
+    pub fn ms_into_slot(&self, slot: u64, timestamp_ns: u64) -> i64 {
+        let slot_start_ms = (self.genesis_time_in_secs as i128 +
+            slot as i128 * self.seconds_per_slot as i128) *
+            1000;
+        let ms_into_slot = (timestamp_ns / 1_000_000) as i128 - slot_start_ms;
+        ms_into_slot.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
     pub fn for_custom(config: String, genesis_validators_root: Node, genesis_time_in_secs: u64) -> Result<Self, Error> {
         let context = Context::try_from_file(&config)?;
         let network = Network::Custom(config.clone());
@@ -150,3 +172,31 @@ impl ChainInfo {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ms_into_slot() {
+        let chain_info = ChainInfo::for_mainnet();
+        let slot = 100;
+        let slot_start_ns = (MAINNET_GENESIS_TIME + slot * chain_info.seconds_per_slot) * 1_000_000_000;
+
+        assert_eq!(chain_info.ms_into_slot(slot, slot_start_ns), 0);
+        assert_eq!(chain_info.ms_into_slot(slot, slot_start_ns + 1_500_000_000), 1_500);
+
+        // Bids received before the slot starts are negative
+        assert_eq!(chain_info.ms_into_slot(slot, slot_start_ns - 250_000_000), -250);
+
+        // Receive times before genesis do not underflow
+        assert_eq!(chain_info.ms_into_slot(0, 0), -(MAINNET_GENESIS_TIME as i64 * 1_000));
+    }
+
+    #[test]
+    fn test_ms_into_slot_far_future_slot_saturates() {
+        let chain_info = ChainInfo::for_mainnet();
+        assert_eq!(chain_info.ms_into_slot(u64::MAX, 0), i64::MIN);
+        assert_eq!(chain_info.ms_into_slot(u64::MAX, u64::MAX), i64::MIN);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{api::*, ValidatorPreferences};
 use clap::Parser;
-use ethereum_consensus::ssz::prelude::Node;
-use helix_utils::request_encoding::Encoding;
+use ethereum_consensus::{
+    primitives::{BlsPublicKey, U256},
+    serde::as_str,
+    ssz::prelude::Node,
+};
+use helix_utils::{request_encoding::Encoding, signer::DEFAULT_REMOTE_SIGNER_TIMEOUT};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, fs::File};
+use std::{collections::HashSet, fmt, fs::File};
 
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct RelayConfig {
@@ -26,6 +30,31 @@ pub struct RelayConfig {
     pub router_config: RouterConfig,
     #[serde(default = "default_duration")]
     pub target_get_payload_propagation_duration_ms: u64,
+    /// Overrides the `get_header` request cutoff derived from the slot duration.
+    #[serde(default)]
+    pub get_header_request_cutoff_ms: Option<u64>,
+    /// How far the local clock may lead the chain before requests are rejected as late. Capped at
+    /// a twelfth of the slot.
+    #[serde(default)]
+    pub clock_skew_tolerance_ms: u64,
+    #[serde(default)]
+    pub builder_reputation: BuilderReputationConfig,
+    #[serde(default)]
+    pub signer: SignerConfig,
+    #[serde(default)]
+    pub builder_access: BuilderAccessConfig,
+    #[serde(default)]
+    pub auctioneer_circuit_breaker: CircuitBreakerConfig,
+    #[serde(default)]
+    pub trace_recorder: TraceRecorderConfig,
+    #[serde(default)]
+    pub retention: RetentionConfig,
+    /// Fetches payloads missing from the datastore from the builder that submitted the bid.
+    #[serde(default)]
+    pub builder_payload_fallback: Option<BuilderPayloadFallbackConfig>,
+    /// Submissions declaring a lower value are rejected before simulation.
+    #[serde(default, with = "as_str")]
+    pub min_bid_value: U256,
 }
 
 impl RelayConfig {
@@ -76,6 +105,79 @@ pub struct FiberConfig {
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct SimulatorConfig {
     pub url: String,
+    /// Caps the number of simulations sent to the simulator at once. Unlimited if not set.
+    #[serde(default)]
+    pub queue: Option<SimulationQueueConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SimulationQueueConfig {
+    pub max_concurrent_simulations: usize,
+    pub policy: SimulationQueuePolicy,
+    /// Submissions still queued after this long are dropped instead of simulated.
+    pub max_wait_ms: u64,
+}
+
+impl Default for SimulationQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_simulations: 32,
+            policy: SimulationQueuePolicy::HighestValue,
+            max_wait_ms: 1_000,
+        }
+    }
+}
+
+/// Order in which queued simulations are let through once capacity frees up.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulationQueuePolicy {
+    /// First come, first served.
+    Fifo,
+    /// Highest declared bid value first, ties in arrival order.
+    #[default]
+    HighestValue,
+}
+
+/// Selects how the relay signs the bids it serves to proposers.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub enum SignerConfig {
+    /// Sign with the key from the `RELAY_KEY` env var.
+    #[default]
+    Local,
+    /// Sign through a Web3Signer instance holding the relay key, see `RemoteSigner`.
+    Remote(RemoteSignerConfig),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RemoteSignerConfig {
+    pub url: String,
+    pub public_key: BlsPublicKey,
+    #[serde(default = "default_remote_signer_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_remote_signer_timeout_ms() -> u64 {
+    DEFAULT_REMOTE_SIGNER_TIMEOUT.as_millis() as u64
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BuilderPayloadFallbackConfig {
+    pub endpoints: Vec<BuilderPayloadEndpoint>,
+    #[serde(default = "default_builder_payload_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+/// Serves `GET {url}/{slot}/{block_hash}` with the JSON encoded payload and blobs of a bid.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BuilderPayloadEndpoint {
+    pub builder_pub_key: BlsPublicKey,
+    pub url: String,
+}
+
+fn default_builder_payload_timeout_ms() -> u64 {
+    500
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -83,6 +185,89 @@ pub struct BeaconClientConfig {
     pub url: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BuilderReputationConfig {
+    /// Rolling window over which simulated submissions are tracked.
+    pub window_ms: u64,
+    /// Minimum number of simulated submissions within the window before a builder can be throttled.
+    pub min_submissions: usize,
+    /// A builder is throttled once the share of invalid submissions in the window exceeds this.
+    pub max_invalid_percentage: u64,
+    /// A builder is throttled once its average simulation latency in the window exceeds this.
+    pub max_avg_simulation_latency_ms: u64,
+    /// Minimum time between two accepted submissions of a throttled builder.
+    pub throttled_submission_interval_ms: u64,
+}
+
+impl Default for BuilderReputationConfig {
+    fn default() -> Self {
+        Self {
+            window_ms: 384_000,
+            min_submissions: 10,
+            max_invalid_percentage: 20,
+            max_avg_simulation_latency_ms: 2_000,
+            throttled_submission_interval_ms: 1_000,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failed or timed out calls after which the breaker opens.
+    pub failure_threshold: u32,
+    /// How long the breaker fails fast before letting a probe call through.
+    pub cooldown_ms: u64,
+    /// Calls taking longer than this are aborted and counted as failures.
+    pub call_timeout_ms: u64,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self { failure_threshold: 5, cooldown_ms: 1_000, call_timeout_ms: 1_000 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct TraceRecorderConfig {
+    /// Keep completed builder submission traces in memory for the admin API.
+    pub enabled: bool,
+    /// Maximum number of traces kept. The oldest trace is dropped once full.
+    pub capacity: usize,
+}
+
+impl Default for TraceRecorderConfig {
+    fn default() -> Self {
+        Self { enabled: false, capacity: 1_000 }
+    }
+}
+
+/// How long bid and payload records are kept in the database. Unset retentions keep records
+/// forever.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct RetentionConfig {
+    /// Number of slots losing bids are kept for. Bids that won an auction are kept with their
+    /// delivered payload.
+    pub bid_retention_slots: Option<u64>,
+    /// Number of slots delivered payloads are kept for.
+    pub delivered_payload_retention_slots: Option<u64>,
+    /// Maximum number of records removed per database transaction.
+    pub prune_chunk_size: u64,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            bid_retention_slots: None,
+            delivered_payload_retention_slots: None,
+            prune_chunk_size: 1_000,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct RelayGossipConfig {
     pub url: String,
@@ -102,6 +287,26 @@ pub enum NetworkConfig {
     },
 }
 
+/// Which builders may submit blocks to the relay.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BuilderAccessMode {
+    /// Any builder may submit.
+    #[default]
+    Open,
+    /// Only the listed builders may submit.
+    Allowlist,
+    /// All builders except the listed ones may submit.
+    Denylist,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct BuilderAccessConfig {
+    pub mode: BuilderAccessMode,
+    #[serde(default)]
+    pub builders: HashSet<BlsPublicKey>,
+}
+
 #[derive(Default, Serialize, Deserialize, Clone)]
 pub enum LoggingConfig {
     #[default]
@@ -122,6 +327,72 @@ pub struct StartConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RouterConfig {
     pub enabled_routes: Vec<RouteInfo>,
+    /// Max number of builder submission bodies buffered at the same time. Submissions above the
+    /// limit are rejected with a `503`. Unlimited if not set.
+    #[serde(default)]
+    pub max_concurrent_body_reads: Option<usize>,
+    /// Compress data API listing responses for clients advertising gzip or brotli support.
+    /// Disabled if not set.
+    #[serde(default)]
+    pub data_api_compression: Option<ResponseCompressionConfig>,
+    /// CORS policy for the read-only data API endpoints. No cross-origin requests are allowed if
+    /// not set.
+    #[serde(default)]
+    pub cors: Option<CorsConfig>,
+    /// Bearer tokens accepted on the admin API routes. Every admin request is rejected with a
+    /// `401` if none is configured.
+    #[serde(default)]
+    pub admin_auth: AdminAuthConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct AdminAuthConfig {
+    /// Any of the tokens is accepted, so a new token can be rolled out before the old one is
+    /// removed.
+    pub tokens: Vec<AdminToken>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AdminToken {
+    /// Identifies the token in logs without revealing it.
+    pub label: String,
+    pub token: String,
+}
+
+impl fmt::Debug for AdminToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdminToken").field("label", &self.label).finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResponseCompressionConfig {
+    /// Responses smaller than this many bytes are sent uncompressed.
+    pub min_size: u16,
+}
+
+impl Default for ResponseCompressionConfig {
+    fn default() -> Self {
+        Self { min_size: 1_024 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    /// Origins allowed to make cross-origin requests, e.g. `https://dashboard.example.com`.
+    /// `https://*.example.com` allows every subdomain of `example.com`.
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self { allowed_origins: Vec::new(), allowed_methods: vec!["GET".to_string()], allowed_headers: Vec::new() }
+    }
 }
 
 impl RouterConfig {
@@ -154,7 +425,13 @@ impl RouterConfig {
 
         self.replace_condensed_with_real(
             Route::ProposerApi,
-            &[Route::Status, Route::RegisterValidators, Route::GetHeader, Route::GetPayload],
+            &[
+                Route::Status,
+                Route::RegisterValidators,
+                Route::GetHeader,
+                Route::GetPayload,
+                Route::SubscribeTopBid,
+            ],
         );
 
         self.replace_condensed_with_real(
@@ -163,6 +440,8 @@ impl RouterConfig {
                 Route::ProposerPayloadDelivered,
                 Route::BuilderBidsReceived,
                 Route::ValidatorRegistration,
+                Route::BuilderReputation,
+                Route::BidsReceived,
             ],
         );
     }
@@ -178,7 +457,7 @@ impl RouterConfig {
     fn extend(&mut self, routes: impl IntoIterator<Item = Route>) {
         for route in routes {
             if !self.contains(route) {
-                self.enabled_routes.push(RouteInfo { route, rate_limit: None });
+                self.enabled_routes.push(RouteInfo { route, rate_limit: None, timeout_ms: None });
             }
         }
     }
@@ -196,6 +475,10 @@ impl RouterConfig {
 pub struct RouteInfo {
     pub route: Route,
     pub rate_limit: Option<RateLimitInfo>,
+    /// Requests to the route running longer than this are aborted with a `504`. Like rate limits,
+    /// only applies to real routes, not condensed ones.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -221,9 +504,18 @@ pub enum Route {
     RegisterValidators,
     GetHeader,
     GetPayload,
+    SubscribeTopBid,
     ProposerPayloadDelivered,
     BuilderBidsReceived,
     ValidatorRegistration,
+    BuilderReputation,
+    BidsReceived,
+    // Admin routes are never part of a condensed route and must be enabled explicitly.
+    UpdateBuilderAccess,
+    RecordedTraces,
+    UpdateMinBidValue,
+    ExportDeliveredPayloads,
+    RefreshProposerDuties,
 }
 
 impl Route {
@@ -238,9 +530,17 @@ impl Route {
             Route::RegisterValidators => format!("{PATH_PROPOSER_API}{PATH_REGISTER_VALIDATORS}"),
             Route::GetHeader => format!("{PATH_PROPOSER_API}{PATH_GET_HEADER}"),
             Route::GetPayload => format!("{PATH_PROPOSER_API}{PATH_GET_PAYLOAD}"),
+            Route::SubscribeTopBid => format!("{PATH_PROPOSER_API}{PATH_SUBSCRIBE_TOP_BID}"),
             Route::ProposerPayloadDelivered => format!("{PATH_DATA_API}{PATH_PROPOSER_PAYLOAD_DELIVERED}"),
             Route::BuilderBidsReceived => format!("{PATH_DATA_API}{PATH_BUILDER_BIDS_RECEIVED}"),
             Route::ValidatorRegistration => format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION}"),
+            Route::BuilderReputation => format!("{PATH_DATA_API}{PATH_BUILDER_REPUTATION}"),
+            Route::BidsReceived => format!("{PATH_DATA_API}{PATH_BIDS_RECEIVED}"),
+            Route::UpdateBuilderAccess => format!("{PATH_ADMIN_API}{PATH_BUILDER_ACCESS}"),
+            Route::RecordedTraces => format!("{PATH_ADMIN_API}{PATH_TRACES}"),
+            Route::UpdateMinBidValue => format!("{PATH_ADMIN_API}{PATH_MIN_BID_VALUE}"),
+            Route::ExportDeliveredPayloads => format!("{PATH_ADMIN_API}{PATH_EXPORT_DELIVERED_PAYLOADS}"),
+            Route::RefreshProposerDuties => format!("{PATH_ADMIN_API}{PATH_REFRESH_PROPOSER_DUTIES}"),
             Route::All => panic!("All is not a real route"),
             Route::BuilderApi => panic!("BuilderApi is not a real route"),
             Route::ProposerApi => panic!("ProposerApi is not a real route"),
@@ -262,6 +562,7 @@ fn test_config() {
     let mut config = RelayConfig::default();
     config.redis.url = "redis://localhost:6379".to_string();
     config.simulator.url = "http://localhost:8080".to_string();
+    config.simulator.queue = Some(SimulationQueueConfig::default());
     config.beacon_clients.push(BeaconClientConfig { url: "http://localhost:8080".to_string() });
     config.broadcasters.push(BroadcasterConfig::BeaconClient(BeaconClientConfig {
         url: "http://localhost:8080".to_string(),
@@ -269,22 +570,44 @@ fn test_config() {
     config.network_config = NetworkConfig::Custom { dir_path: "test".to_string(), genesis_validator_root: Default::default(), genesis_time: 1 };
     config.logging =
         LoggingConfig::File { dir_path: "hello".to_string(), file_name: "test".to_string() };
+    config.clock_skew_tolerance_ms = 100;
+    config.retention = RetentionConfig {
+        bid_retention_slots: Some(7_200),
+        delivered_payload_retention_slots: Some(216_000),
+        ..Default::default()
+    };
+    config.builder_payload_fallback = Some(BuilderPayloadFallbackConfig {
+        endpoints: vec![BuilderPayloadEndpoint {
+            builder_pub_key: Default::default(),
+            url: "http://localhost:8081/payloads".to_string(),
+        }],
+        timeout_ms: default_builder_payload_timeout_ms(),
+    });
     config.validator_preferences = ValidatorPreferences { filtering: Filtering::Regional, trusted_builders: None, header_delay: true};
     config.router_config = RouterConfig {
         enabled_routes: vec![
-            RouteInfo { route: Route::GetValidators, rate_limit: None },
-            RouteInfo { route: Route::SubmitBlock, rate_limit: None },
-            RouteInfo { route: Route::SubmitBlockOptimistic, rate_limit: None },
-            RouteInfo { route: Route::ValidatorRegistration, rate_limit: None },
-            RouteInfo { route: Route::GetHeader, rate_limit: Some(RateLimitInfo { limit_duration_ms: 12, max_requests: 3 }) },
-            RouteInfo { route: Route::GetPayload, rate_limit: None },
-            RouteInfo { route: Route::ProposerPayloadDelivered, rate_limit: None },
-            RouteInfo { route: Route::RegisterValidators, rate_limit: None },
-            RouteInfo { route: Route::Status, rate_limit: None },
+            RouteInfo { route: Route::GetValidators, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::SubmitBlock, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::SubmitBlockOptimistic, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::ValidatorRegistration, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::GetHeader, rate_limit: Some(RateLimitInfo { limit_duration_ms: 12, max_requests: 3 }), timeout_ms: Some(1_000) },
+            RouteInfo { route: Route::GetPayload, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::ProposerPayloadDelivered, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::RegisterValidators, rate_limit: None, timeout_ms: None },
+            RouteInfo { route: Route::Status, rate_limit: None, timeout_ms: None },
         ]
         .iter()
         .cloned()
         .collect(),
+        max_concurrent_body_reads: Some(64),
+        data_api_compression: Some(ResponseCompressionConfig::default()),
+        cors: Some(CorsConfig {
+            allowed_origins: vec!["https://*.example.com".to_string()],
+            ..Default::default()
+        }),
+        admin_auth: AdminAuthConfig {
+            tokens: vec![AdminToken { label: "ops-2024".to_string(), token: "secret".to_string() }],
+        },
     };
     println!("{}", serde_yaml::to_string(&config).unwrap());
 }
@@ -1,4 +1,5 @@
 use ethereum_consensus::primitives::{BlsPublicKey, BlsSignature};
+use helix_utils::signer::BuilderMessage;
 pub use ethereum_consensus::{
     bellatrix::mainnet as spec, builder::SignedValidatorRegistration, serde::as_str,
     ssz::prelude::*,
@@ -17,6 +18,10 @@ pub struct BuilderBid {
     pub public_key: BlsPublicKey,
 }
 
+impl BuilderMessage for BuilderBid {
+    const SIGN_TYPE: &'static str = "BUILDER_BID";
+}
+
 #[derive(Debug, Default, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
 pub struct SignedBuilderBid {
     pub message: BuilderBid,
@@ -6,6 +6,7 @@ use ethereum_consensus::{
     primitives::{BlsPublicKey, BlsSignature, U256},
     ssz::prelude::*,
 };
+use helix_utils::signer::BuilderMessage;
 
 pub type ExecutionPayload = spec::ExecutionPayload;
 pub type ExecutionPayloadHeader = spec::ExecutionPayloadHeader;
@@ -20,6 +21,10 @@ pub struct BuilderBid {
     pub public_key: BlsPublicKey,
 }
 
+impl BuilderMessage for BuilderBid {
+    const SIGN_TYPE: &'static str = "BUILDER_BID";
+}
+
 #[derive(Debug, Default, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
 pub struct SignedBuilderBid {
     pub message: BuilderBid,
@@ -10,6 +10,7 @@ use ethereum_consensus::{
     ssz::prelude::*,
     types::mainnet::SignedBeaconBlock,
 };
+use helix_utils::signer::BuilderMessage;
 
 pub type ExecutionPayload = spec::ExecutionPayload;
 pub type ExecutionPayloadHeader = spec::ExecutionPayloadHeader;
@@ -34,6 +35,10 @@ pub struct BlindedBlobsBundle {
     pub blob_roots: List<Root, MAX_BLOB_COMMITMENTS_PER_BLOCK>,
 }
 
+impl BuilderMessage for BuilderBid {
+    const SIGN_TYPE: &'static str = "BUILDER_BID";
+}
+
 #[derive(Debug, Default, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
 pub struct SignedBuilderBid {
     pub message: BuilderBid,
@@ -6,15 +6,14 @@ pub mod versioned_payload;
 pub mod versioned_payload_header;
 
 use ethereum_consensus::{
-    crypto::SecretKey,
     primitives::{BlsPublicKey, Hash32, Slot},
     ssz::prelude::*,
     state_transition::Context,
     types::mainnet::{ExecutionPayload, ExecutionPayloadHeader},
-    Error,
+    Error, Fork,
 };
 
-use helix_utils::signing::sign_builder_message;
+use helix_utils::signer::{sign_builder_message_with, RelaySigner, SignerError};
 
 use crate::bid_submission::{
     v2::header_submission::SignedHeaderSubmission, BidSubmission, SignedBidSubmission,
@@ -57,30 +56,33 @@ impl std::fmt::Display for SignedBuilderBid {
 }
 
 impl SignedBuilderBid {
-    pub fn from_submission(
+    pub async fn from_submission(
         submission: &mut SignedBidSubmission,
         public_key: BlsPublicKey,
-        signing_key: &SecretKey,
+        signer: &dyn RelaySigner,
         context: &Context,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, SignerError> {
         match &mut submission.execution_payload_mut() {
             ExecutionPayload::Bellatrix(payload) => {
-                let header = bellatrix::ExecutionPayloadHeader::try_from(payload)?;
+                let header =
+                    bellatrix::ExecutionPayloadHeader::try_from(payload).map_err(Error::from)?;
                 let mut message =
                     bellatrix::BuilderBid { header, value: submission.value(), public_key };
-                let signature = sign_builder_message(&mut message, signing_key, context)?;
+                let signature = sign_builder_message_with(&mut message, signer, context).await?;
 
                 Ok(Self::Bellatrix(bellatrix::SignedBuilderBid { message, signature }))
             }
             ExecutionPayload::Capella(payload) => {
-                let header = capella::ExecutionPayloadHeader::try_from(payload)?;
+                let header =
+                    capella::ExecutionPayloadHeader::try_from(payload).map_err(Error::from)?;
                 let mut message =
                     capella::BuilderBid { header, value: submission.value(), public_key };
-                let signature = sign_builder_message(&mut message, signing_key, context)?;
+                let signature = sign_builder_message_with(&mut message, signer, context).await?;
                 Ok(Self::Capella(capella::SignedBuilderBid { message, signature }))
             }
             ExecutionPayload::Deneb(payload) => {
-                let header = deneb::ExecutionPayloadHeader::try_from(payload)?;
+                let header =
+                    deneb::ExecutionPayloadHeader::try_from(payload).map_err(Error::from)?;
                 match submission.blobs_bundle() {
                     Some(blobs_bundle) => {
                         let mut message = deneb::BuilderBid {
@@ -89,26 +91,27 @@ impl SignedBuilderBid {
                             value: submission.value(),
                             public_key,
                         };
-                        let signature = sign_builder_message(&mut message, signing_key, context)?;
+                        let signature =
+                            sign_builder_message_with(&mut message, signer, context).await?;
 
                         Ok(Self::Deneb(deneb::SignedBuilderBid { message, signature }))
                     }
-                    None => Err(std::io::Error::new(
+                    None => Err(Error::from(std::io::Error::new(
                         std::io::ErrorKind::InvalidInput,
                         "Missing blobs bundle",
-                    )
+                    ))
                     .into()),
                 }
             }
         }
     }
 
-    pub fn from_header_submission(
+    pub async fn from_header_submission(
         submission: &SignedHeaderSubmission,
         public_key: BlsPublicKey,
-        signing_key: &SecretKey,
+        signer: &dyn RelaySigner,
         context: &Context,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, SignerError> {
         match &submission.execution_payload_header() {
             ExecutionPayloadHeader::Bellatrix(header) => {
                 let mut message = bellatrix::BuilderBid {
@@ -116,7 +119,7 @@ impl SignedBuilderBid {
                     value: submission.value(),
                     public_key,
                 };
-                let signature = sign_builder_message(&mut message, signing_key, context)?;
+                let signature = sign_builder_message_with(&mut message, signer, context).await?;
 
                 Ok(Self::Bellatrix(bellatrix::SignedBuilderBid { message, signature }))
             }
@@ -126,7 +129,7 @@ impl SignedBuilderBid {
                     value: submission.value(),
                     public_key,
                 };
-                let signature = sign_builder_message(&mut message, signing_key, context)?;
+                let signature = sign_builder_message_with(&mut message, signer, context).await?;
                 Ok(Self::Capella(capella::SignedBuilderBid { message, signature }))
             }
             ExecutionPayloadHeader::Deneb(header) => match submission.commitments() {
@@ -137,14 +140,14 @@ impl SignedBuilderBid {
                         value: submission.value(),
                         public_key,
                     };
-                    let signature = sign_builder_message(&mut message, signing_key, context)?;
+                    let signature = sign_builder_message_with(&mut message, signer, context).await?;
 
                     Ok(Self::Deneb(deneb::SignedBuilderBid { message, signature }))
                 }
-                None => Err(std::io::Error::new(
+                None => Err(Error::from(std::io::Error::new(
                     std::io::ErrorKind::InvalidInput,
                     "Missing blobs bundle",
-                )
+                ))
                 .into()),
             },
         }
@@ -189,6 +192,23 @@ impl SignedBuilderBid {
             Self::Deneb(bid) => &bid.message.header.logs_bloom,
         }
     }
+
+    pub fn version(&self) -> Fork {
+        match self {
+            Self::Bellatrix(_) => Fork::Bellatrix,
+            Self::Capella(_) => Fork::Capella,
+            Self::Deneb(_) => Fork::Deneb,
+        }
+    }
+
+    /// SSZ encodes the bid for its fork. Unlike the JSON encoding, the version is not included.
+    pub fn to_ssz_bytes(&self) -> Result<Vec<u8>, SerializeError> {
+        match self {
+            Self::Bellatrix(bid) => serialize(bid),
+            Self::Capella(bid) => serialize(bid),
+            Self::Deneb(bid) => serialize(bid),
+        }
+    }
 }
 
 pub fn try_execution_header_from_payload(
@@ -1,11 +1,23 @@
-use ethereum_consensus::{
-    crypto::SecretKey,
-    deneb::{BlsPublicKey, Context},
-};
+use std::sync::Arc;
 
-#[derive(Clone, Default)]
+use ethereum_consensus::deneb::{BlsPublicKey, Context};
+use helix_utils::signer::{LocalSigner, RelaySigner};
+
+#[derive(Clone)]
 pub struct RelaySigningContext {
     pub public_key: BlsPublicKey,
-    pub signing_key: SecretKey,
+    pub signer: Arc<dyn RelaySigner>,
     pub context: Context,
 }
+
+impl RelaySigningContext {
+    pub fn new(signer: Arc<dyn RelaySigner>, context: Context) -> Self {
+        Self { public_key: signer.public_key().clone(), signer, context }
+    }
+}
+
+impl Default for RelaySigningContext {
+    fn default() -> Self {
+        Self::new(Arc::new(LocalSigner::default()), Context::default())
+    }
+}
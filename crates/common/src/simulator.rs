@@ -18,6 +18,9 @@ pub enum BlockSimError {
 
     #[error("tokio::mpsc send error")]
     SendError,
+
+    #[error("simulation queue wait exceeded")]
+    SimulationQueueTimeout,
 }
 
 impl BlockSimError {
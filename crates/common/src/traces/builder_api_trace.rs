@@ -8,6 +8,8 @@ pub struct SubmissionTrace {
     pub simulation: u64,
     pub auctioneer_update: u64,
     pub request_finish: u64,
+    /// Time of `receive` relative to the start of the bid's slot, negative if received earlier.
+    pub ms_into_slot: i64,
 }
 
 #[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
@@ -19,6 +21,8 @@ pub struct HeaderSubmissionTrace {
     pub floor_bid_checks: u64,
     pub auctioneer_update: u64,
     pub request_finish: u64,
+    /// Time of `receive` relative to the start of the bid's slot, negative if received earlier.
+    pub ms_into_slot: i64,
 }
 
 #[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
@@ -1,5 +1,7 @@
 pub mod builder_api_trace;
 pub mod proposer_api;
+pub mod recorder;
 
 pub use builder_api_trace::*;
 pub use proposer_api::*;
+pub use recorder::*;
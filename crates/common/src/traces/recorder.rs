@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{HeaderSubmissionTrace, SubmissionTrace};
+
+/// How a recorded request finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceOutcome {
+    /// The bid was saved to the auctioneer.
+    BidSaved,
+    /// The request completed but the bid was not saved, e.g. it was not the top bid.
+    BidNotSaved,
+    /// The submission failed a check and was rejected, see `TraceRecord::error`.
+    Rejected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "timestamps", rename_all = "snake_case")]
+pub enum RecordedTrace {
+    Submission(SubmissionTrace),
+    HeaderSubmission(HeaderSubmissionTrace),
+}
+
+impl RecordedTrace {
+    /// All checkpoints that were reached, ordered by timestamp.
+    fn checkpoints(&self) -> Vec<(&'static str, u64)> {
+        let mut checkpoints = match self {
+            RecordedTrace::Submission(trace) => vec![
+                ("receive", trace.receive),
+                ("decode", trace.decode),
+                ("floor_bid_checks", trace.floor_bid_checks),
+                ("pre_checks", trace.pre_checks),
+                ("signature", trace.signature),
+                ("simulation", trace.simulation),
+                ("auctioneer_update", trace.auctioneer_update),
+                ("request_finish", trace.request_finish),
+            ],
+            RecordedTrace::HeaderSubmission(trace) => vec![
+                ("receive", trace.receive),
+                ("decode", trace.decode),
+                ("pre_checks", trace.pre_checks),
+                ("signature", trace.signature),
+                ("floor_bid_checks", trace.floor_bid_checks),
+                ("auctioneer_update", trace.auctioneer_update),
+                ("request_finish", trace.request_finish),
+            ],
+        };
+        checkpoints.retain(|(_, timestamp)| *timestamp != 0);
+        checkpoints.sort_by_key(|(_, timestamp)| *timestamp);
+        checkpoints
+    }
+
+    /// Time spent between each pair of consecutive checkpoints.
+    pub fn segments(&self) -> Vec<TraceSegment> {
+        self.checkpoints()
+            .windows(2)
+            .map(|window| TraceSegment {
+                from: window[0].0.to_string(),
+                to: window[1].0.to_string(),
+                duration_ns: window[1].1 - window[0].1,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceSegment {
+    pub from: String,
+    pub to: String,
+    pub duration_ns: u64,
+}
+
+/// A completed request trace kept for post-mortem analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRecord {
+    pub request_id: String,
+    pub slot: u64,
+    pub outcome: TraceOutcome,
+    /// Why the submission was rejected, set for `TraceOutcome::Rejected` only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub trace: RecordedTrace,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRecordResponse {
+    #[serde(flatten)]
+    pub record: TraceRecord,
+    pub segments: Vec<TraceSegment>,
+}
+
+impl From<TraceRecord> for TraceRecordResponse {
+    fn from(record: TraceRecord) -> Self {
+        let segments = record.trace.segments();
+        Self { record, segments }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_skip_unset_checkpoints() {
+        let trace = RecordedTrace::Submission(SubmissionTrace {
+            receive: 100,
+            decode: 150,
+            pre_checks: 300,
+            signature: 350,
+            request_finish: 1_000,
+            ..Default::default()
+        });
+
+        let segments = trace.segments();
+        let durations: Vec<_> = segments
+            .iter()
+            .map(|segment| (segment.from.as_str(), segment.to.as_str(), segment.duration_ns))
+            .collect();
+        assert_eq!(
+            durations,
+            vec![
+                ("receive", "decode", 50),
+                ("decode", "pre_checks", 150),
+                ("pre_checks", "signature", 50),
+                ("signature", "request_finish", 650),
+            ]
+        );
+    }
+}
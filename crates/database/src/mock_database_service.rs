@@ -26,6 +26,7 @@ use crate::{
 pub struct MockDatabaseService {
     known_validators: Arc<Mutex<Vec<ValidatorSummary>>>,
     proposer_duties: Arc<Mutex<Vec<BuilderGetValidatorsResponseEntry>>>,
+    delivered_payloads: Arc<Mutex<Vec<DeliveredPayloadDocument>>>,
 }
 
 impl MockDatabaseService {
@@ -33,7 +34,13 @@ impl MockDatabaseService {
         known_validators: Arc<Mutex<Vec<ValidatorSummary>>>,
         proposer_duties: Arc<Mutex<Vec<BuilderGetValidatorsResponseEntry>>>,
     ) -> Self {
-        Self { known_validators, proposer_duties }
+        Self { known_validators, proposer_duties, delivered_payloads: Default::default() }
+    }
+
+    /// Seeds the delivered payloads returned by `get_delivered_payloads`. Until seeded, a single
+    /// default payload is returned for every query.
+    pub fn set_delivered_payloads(&self, payloads: Vec<DeliveredPayloadDocument>) {
+        *self.delivered_payloads.lock().unwrap() = payloads;
     }
 }
 
@@ -98,7 +105,7 @@ impl DatabaseService for MockDatabaseService {
     async fn get_proposer_duties(
         &self,
     ) -> Result<Vec<BuilderGetValidatorsResponseEntry>, DatabaseError> {
-        Ok(vec![])
+        Ok(self.proposer_duties.lock().unwrap().clone())
     }
 
     async fn set_known_validators(
@@ -204,11 +211,49 @@ impl DatabaseService for MockDatabaseService {
         Ok(vec![bid])
     }
 
+    async fn get_bids_received(
+        &self,
+        slot: u64,
+        _cursor: Option<Hash32>,
+        limit: u64,
+    ) -> Result<Vec<BidSubmissionDocument>, DatabaseError> {
+        // Deliberately unordered so callers can't rely on the mock for the value ordering
+        let bids = [2000, 1000, 3000]
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let mut bid = BidSubmissionDocument::default();
+                bid.bid_trace.slot = slot;
+                bid.bid_trace.block_hash = Hash32::try_from([i as u8 + 1; 32].as_ref()).unwrap();
+                bid.bid_trace.value = U256::from(value);
+                bid
+            })
+            .take(limit as usize)
+            .collect();
+        Ok(bids)
+    }
+
     async fn get_delivered_payloads(
         &self,
-        _filters: &BidFilters,
+        filters: &BidFilters,
         _validator_preferences: Arc<ValidatorPreferences>,
     ) -> Result<Vec<DeliveredPayloadDocument>, DatabaseError> {
+        let delivered_payloads = self.delivered_payloads.lock().unwrap();
+        if !delivered_payloads.is_empty() {
+            let mut payloads = delivered_payloads
+                .iter()
+                .filter(|payload| {
+                    let slot = payload.bid_trace.slot;
+                    filters.from_slot.map_or(true, |from_slot| slot >= from_slot) &&
+                        filters.to_slot.map_or(true, |to_slot| slot <= to_slot)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            // Newest first, matching the postgres ordering
+            payloads.sort_by(|a, b| b.bid_trace.slot.cmp(&a.bid_trace.slot));
+            return Ok(payloads);
+        }
+
         let doc = DeliveredPayloadDocument {
             bid_trace: Default::default(),
             block_number: 0,
@@ -276,4 +321,16 @@ impl DatabaseService for MockDatabaseService {
             Ok(None)
         }
     }
+
+    async fn prune_bids(&self, _before_slot: u64, _chunk_size: u64) -> Result<u64, DatabaseError> {
+        Ok(0)
+    }
+
+    async fn prune_delivered_payloads(
+        &self,
+        _before_slot: u64,
+        _chunk_size: u64,
+    ) -> Result<u64, DatabaseError> {
+        Ok(0)
+    }
 }
@@ -0,0 +1,5 @@
+ALTER TABLE submission_trace
+ADD COLUMN "ms_into_slot" bigint;
+
+ALTER TABLE header_submission_trace
+ADD COLUMN "ms_into_slot" bigint;
@@ -24,6 +24,14 @@ impl PgBidFilters {
         self.0.cursor.map(|cursor| cursor as i32)
     }
 
+    pub fn from_slot(&self) -> Option<i32> {
+        self.0.from_slot.map(|from_slot| from_slot as i32)
+    }
+
+    pub fn to_slot(&self) -> Option<i32> {
+        self.0.to_slot.map(|to_slot| to_slot as i32)
+    }
+
     pub fn block_number(&self) -> Option<i32> {
         self.0.block_number.map(|block_number| block_number as i32)
     }
@@ -338,6 +338,52 @@ impl PostgresDatabaseService {
 
         Ok(())
     }
+
+    /// Removes rows of `submission_table` for slots before `before_slot` whose block was never
+    /// delivered, along with the rows of `related_tables` for the same block hashes.
+    async fn prune_undelivered_submissions(
+        &self,
+        submission_table: &str,
+        related_tables: &[&str],
+        before_slot: u64,
+        chunk_size: u64,
+    ) -> Result<u64, DatabaseError> {
+        let mut client = self.pool.get().await?;
+        let delete_submissions = format!(
+            "
+                DELETE FROM {submission_table}
+                WHERE block_hash IN (
+                    SELECT block_hash FROM {submission_table}
+                    WHERE slot_number < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = {submission_table}.block_hash
+                    )
+                    LIMIT $2
+                )
+                RETURNING block_hash
+            "
+        );
+
+        let mut num_removed = 0;
+        loop {
+            let transaction = client.transaction().await?;
+            let rows = transaction
+                .query(&delete_submissions, &[&(before_slot as i32), &(chunk_size as i64)])
+                .await?;
+            let block_hashes: Vec<Vec<u8>> = rows.iter().map(|row| row.get("block_hash")).collect();
+            for table in related_tables {
+                let sql = format!("DELETE FROM {table} WHERE block_hash = ANY($1::bytea[])");
+                transaction.execute(&sql, &[&block_hashes]).await?;
+            }
+            transaction.commit().await?;
+
+            num_removed += block_hashes.len() as u64;
+            if (block_hashes.len() as u64) < chunk_size {
+                return Ok(num_removed);
+            }
+        }
+    }
 }
 
 impl Default for PostgresDatabaseService {
@@ -1019,9 +1065,9 @@ impl DatabaseService for PostgresDatabaseService {
         transaction.execute(
             "
                 INSERT INTO
-                    submission_trace (block_hash, region_id, optimistic_version, receive, decode, pre_checks, signature, floor_bid_checks, simulation, auctioneer_update, request_finish)
+                    submission_trace (block_hash, region_id, optimistic_version, receive, decode, pre_checks, signature, floor_bid_checks, simulation, auctioneer_update, request_finish, ms_into_slot)
                 VALUES
-                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             ",
             &[
                 &(submission.block_hash().as_ref()),
@@ -1035,6 +1081,7 @@ impl DatabaseService for PostgresDatabaseService {
                 &(trace.simulation as i64),
                 &(trace.auctioneer_update as i64),
                 &(trace.request_finish as i64),
+                &(trace.ms_into_slot),
             ],
         ).await?;
 
@@ -1249,6 +1296,67 @@ impl DatabaseService for PostgresDatabaseService {
         )
     }
 
+    async fn get_bids_received(
+        &self,
+        slot: u64,
+        cursor: Option<Hash32>,
+        limit: u64,
+    ) -> Result<Vec<BidSubmissionDocument>, DatabaseError> {
+        let mut query = String::from("
+            SELECT
+                block_submission.block_number block_number,
+                block_submission.slot_number slot_number,
+                block_submission.parent_hash,
+                block_submission.block_hash,
+                block_submission.builder_pubkey builder_public_key,
+                block_submission.proposer_pubkey proposer_public_key,
+                block_submission.proposer_fee_recipient proposer_fee_recipient,
+                block_submission.gas_limit gas_limit,
+                block_submission.gas_used gas_used,
+                block_submission.value submission_value,
+                block_submission.num_txs num_txs,
+                LEAST(block_submission.first_seen, header_submission.first_seen) submission_timestamp
+            FROM
+                block_submission
+            LEFT JOIN
+                header_submission ON block_submission.block_hash = header_submission.block_hash
+            WHERE block_submission.slot_number = $1
+        ");
+
+        let mut param_index = 2;
+        let mut params: Vec<Box<dyn ToSql + Sync + Send>> = vec![Box::new(slot as i32)];
+
+        // Keyset pagination on (value, block_hash) so new bids arriving between pages
+        // don't shift the results.
+        if let Some(cursor) = cursor {
+            query.push_str(&format!("
+                AND (block_submission.value, block_submission.block_hash) < (
+                    SELECT cursor_submission.value, cursor_submission.block_hash
+                    FROM block_submission cursor_submission
+                    WHERE cursor_submission.block_hash = ${}
+                )
+            ", param_index));
+            params.push(Box::new(cursor.to_vec()));
+            param_index += 1;
+        }
+
+        query.push_str(&format!(
+            " ORDER BY block_submission.value DESC, block_submission.block_hash DESC LIMIT ${}",
+            param_index
+        ));
+        params.push(Box::new(limit as i64));
+
+        let params_refs: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+        parse_rows(
+            self.pool
+                .get()
+                .await?
+                .query(&query, &params_refs[..])
+                .await?,
+        )
+    }
+
     async fn get_delivered_payloads(
         &self,
         filters: &BidFilters,
@@ -1307,6 +1415,18 @@ impl DatabaseService for PostgresDatabaseService {
             param_index += 1;
         }
 
+        if let Some(from_slot) = filters.from_slot() {
+            query.push_str(&format!(" AND block_submission.slot_number >= ${}", param_index));
+            params.push(Box::new(from_slot));
+            param_index += 1;
+        }
+
+        if let Some(to_slot) = filters.to_slot() {
+            query.push_str(&format!(" AND block_submission.slot_number <= ${}", param_index));
+            params.push(Box::new(to_slot));
+            param_index += 1;
+        }
+
         if let Some(block_number) = filters.block_number() {
             query.push_str(&format!(" AND block_submission.block_number = ${}", param_index));
             params.push(Box::new(block_number));
@@ -1508,9 +1628,9 @@ impl DatabaseService for PostgresDatabaseService {
         transaction.execute(
             "
                 INSERT INTO
-                    header_submission_trace (block_hash, region_id, receive, decode, pre_checks, signature, floor_bid_checks, auctioneer_update, request_finish)
+                    header_submission_trace (block_hash, region_id, receive, decode, pre_checks, signature, floor_bid_checks, auctioneer_update, request_finish, ms_into_slot)
                 VALUES
-                    ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             ",
             &[
                 &(submission.block_hash().as_ref()),
@@ -1522,6 +1642,7 @@ impl DatabaseService for PostgresDatabaseService {
                 &(trace.floor_bid_checks as i64),
                 &(trace.auctioneer_update as i64),
                 &(trace.request_finish as i64),
+                &(trace.ms_into_slot),
             ],
         ).await?;
 
@@ -1595,4 +1716,82 @@ impl DatabaseService for PostgresDatabaseService {
                 .await?,
         )
     }
+
+    async fn prune_bids(&self, before_slot: u64, chunk_size: u64) -> Result<u64, DatabaseError> {
+        let chunk_size = chunk_size.max(1);
+        let num_bids = self
+            .prune_undelivered_submissions(
+                "block_submission",
+                &["submission_trace", "simulation_error"],
+                before_slot,
+                chunk_size,
+            )
+            .await?;
+        let num_headers = self
+            .prune_undelivered_submissions(
+                "header_submission",
+                &["header_submission_trace"],
+                before_slot,
+                chunk_size,
+            )
+            .await?;
+        Ok(num_bids + num_headers)
+    }
+
+    async fn prune_delivered_payloads(
+        &self,
+        before_slot: u64,
+        chunk_size: u64,
+    ) -> Result<u64, DatabaseError> {
+        let chunk_size = chunk_size.max(1);
+        let mut client = self.pool.get().await?;
+
+        let mut num_removed = 0;
+        loop {
+            let transaction = client.transaction().await?;
+            let rows = transaction
+                .query(
+                    "
+                    DELETE FROM delivered_payload
+                    WHERE block_hash IN (
+                        SELECT block_submission.block_hash FROM block_submission
+                        INNER JOIN delivered_payload
+                            ON delivered_payload.block_hash = block_submission.block_hash
+                        WHERE block_submission.slot_number < $1
+                        UNION
+                        SELECT header_submission.block_hash FROM header_submission
+                        INNER JOIN delivered_payload
+                            ON delivered_payload.block_hash = header_submission.block_hash
+                        WHERE header_submission.slot_number < $1
+                        LIMIT $2
+                    )
+                    RETURNING block_hash
+                ",
+                    &[&(before_slot as i32), &(chunk_size as i64)],
+                )
+                .await?;
+            let block_hashes: Vec<Vec<u8>> = rows.iter().map(|row| row.get("block_hash")).collect();
+
+            for table in [
+                "delivered_payload_preferences",
+                "payload_trace",
+                "transaction",
+                "withdrawal",
+                "block_submission",
+                "submission_trace",
+                "simulation_error",
+                "header_submission",
+                "header_submission_trace",
+            ] {
+                let sql = format!("DELETE FROM {table} WHERE block_hash = ANY($1::bytea[])");
+                transaction.execute(&sql, &[&block_hashes]).await?;
+            }
+            transaction.commit().await?;
+
+            num_removed += block_hashes.len() as u64;
+            if (block_hashes.len() as u64) < chunk_size {
+                return Ok(num_removed);
+            }
+        }
+    }
 }
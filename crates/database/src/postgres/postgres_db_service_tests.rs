@@ -7,9 +7,12 @@ mod tests {
     use rand::{seq::SliceRandom, thread_rng};
     use rand::Rng;
     use tokio::time::sleep;
-    use crate::{postgres::postgres_db_service::PostgresDatabaseService, DatabaseService};
+    use crate::{
+        postgres::postgres_db_service::PostgresDatabaseService, DatabaseService,
+        DeliveredPayloadDocument,
+    };
     use ethereum_consensus::{
-        builder::{SignedValidatorRegistration, ValidatorRegistration}, clock::get_current_unix_time_in_nanos, crypto::SecretKey, primitives::U256
+        builder::{SignedValidatorRegistration, ValidatorRegistration}, clock::get_current_unix_time_in_nanos, crypto::SecretKey, primitives::{BlsPublicKey, U256}
     };
     use helix_common::{
         bellatrix::{ByteList, ByteVector, List}, bid_submission::{
@@ -29,8 +32,10 @@ mod tests {
     use ethereum_consensus::phase0::Validator;
     use helix_common::{
         api::{
-            builder_api::BuilderGetValidatorsResponseEntry, proposer_api::ValidatorRegistrationInfo,
+            builder_api::BuilderGetValidatorsResponseEntry, data_api::BidFilters,
+            proposer_api::ValidatorRegistrationInfo,
         },
+        chain_info::ChainInfo,
         simulator::BlockSimError,
         validator_preferences::ValidatorPreferences,
     };
@@ -466,6 +471,51 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_store_block_submission_ms_into_slot() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 1)?;
+        let client = setup_test_conn().await?;
+
+        let chain_info = ChainInfo::for_mainnet();
+        let slot = 1236;
+        let random_bytes: [u8; 32] = rand::thread_rng().gen();
+        let bid_trace = BidTrace {
+            slot,
+            block_hash: ByteVector::<32>::try_from(random_bytes.as_slice()).unwrap(),
+            ..Default::default()
+        };
+        let mut signed_bid_submission = SignedBidSubmission::default();
+        match &mut signed_bid_submission {
+            SignedBidSubmission::Deneb(submission) => {
+                submission.message = bid_trace.clone();
+            }
+            SignedBidSubmission::Capella(submission) => {
+                submission.message = bid_trace.clone();
+            }
+        }
+
+        // Received 250ms before the slot starts
+        let slot_start_ns =
+            (chain_info.genesis_time_in_secs + slot * chain_info.seconds_per_slot) * 1_000_000_000;
+        let mut submission_trace = SubmissionTrace::default();
+        submission_trace.receive = slot_start_ns - 250_000_000;
+        submission_trace.ms_into_slot = chain_info.ms_into_slot(slot, submission_trace.receive);
+
+        db_service
+            .store_block_submission(Arc::new(signed_bid_submission), Arc::new(submission_trace), 0)
+            .await?;
+
+        let row = client
+            .query_one(
+                "SELECT ms_into_slot FROM submission_trace WHERE block_hash = $1",
+                &[&(bid_trace.block_hash.as_ref())],
+            )
+            .await?;
+        assert_eq!(row.get::<_, i64>("ms_into_slot"), -250);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_get_bids() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -473,6 +523,8 @@ mod tests {
         let filter = helix_common::api::data_api::BidFilters {
             slot: Some(1234),
             cursor: None,
+            from_slot: None,
+            to_slot: None,
             limit: None,
             block_hash: None,
             block_number: None,
@@ -485,6 +537,55 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_get_bids_received() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 1)?;
+
+        let slot = rand::thread_rng().gen_range(1_000_000..2_000_000);
+        for value in [2000, 3000, 1000] {
+            let random_bytes: [u8; 32] = rand::thread_rng().gen();
+            let bid_trace = BidTrace {
+                slot,
+                block_hash: ByteVector::<32>::try_from(random_bytes.as_slice()).unwrap(),
+                value: U256::from(value),
+                ..Default::default()
+            };
+            let mut signed_bid_submission = SignedBidSubmission::default();
+            match &mut signed_bid_submission {
+                SignedBidSubmission::Deneb(submission) => {
+                    submission.message = bid_trace.clone();
+                }
+                SignedBidSubmission::Capella(submission) => {
+                    submission.message = bid_trace.clone();
+                }
+            }
+
+            let mut submission_trace = SubmissionTrace::default();
+            submission_trace.receive = get_current_unix_time_in_nanos() as u64;
+
+            db_service
+                .store_block_submission(
+                    Arc::new(signed_bid_submission),
+                    Arc::new(submission_trace),
+                    0,
+                )
+                .await?;
+        }
+
+        let bids = db_service.get_bids_received(slot, None, 10).await?;
+        let values = bids.iter().map(|bid| bid.bid_trace.value).collect::<Vec<_>>();
+        assert_eq!(values, vec![U256::from(3000), U256::from(2000), U256::from(1000)]);
+
+        let first_page = db_service.get_bids_received(slot, None, 2).await?;
+        assert_eq!(first_page.len(), 2);
+        let cursor = first_page.last().map(|bid| bid.bid_trace.block_hash.clone());
+        let second_page = db_service.get_bids_received(slot, cursor, 2).await?;
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].bid_trace.value, U256::from(1000));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_save_delivered_payloads() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -549,6 +650,8 @@ mod tests {
         let filter = helix_common::api::data_api::BidFilters {
             slot: None,
             cursor: None,
+            from_slot: None,
+            to_slot: None,
             limit: None,
             block_hash: None,
             block_number: None,
@@ -564,6 +667,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_get_delivered_payloads_filters() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 0)?;
+
+        // A fresh builder key and slot range keep the results independent of other tests
+        let mut rng = rand::thread_rng();
+        let builder = SecretKey::random(&mut rng).unwrap().public_key();
+        let other_builder = SecretKey::random(&mut rng).unwrap().public_key();
+        let base_slot = rng.gen_range(1_000_000..100_000_000);
+
+        let mut delivered = Vec::new();
+        for (offset, slot_builder) in
+            [(0, &builder), (1, &builder), (2, &other_builder), (3, &builder)]
+        {
+            let slot = base_slot + offset;
+            let block_hash = store_bid_from_builder(&db_service, slot, slot_builder).await?;
+            deliver_bid(&db_service, slot, &block_hash).await?;
+            delivered.push(block_hash);
+        }
+        let block_hashes = |payloads: &[DeliveredPayloadDocument]| {
+            payloads.iter().map(|payload| payload.bid_trace.block_hash.clone()).collect::<Vec<_>>()
+        };
+        let preferences = Arc::new(ValidatorPreferences::default());
+
+        // Builder only, newest first
+        let filters = BidFilters { builder_pubkey: Some(builder.clone()), ..Default::default() };
+        let payloads = db_service.get_delivered_payloads(&filters, preferences.clone()).await?;
+        assert_eq!(
+            block_hashes(&payloads),
+            vec![delivered[3].clone(), delivered[1].clone(), delivered[0].clone()]
+        );
+        assert!(payloads.iter().all(|payload| payload.bid_trace.builder_public_key == builder));
+
+        // Slot range only, bounds are inclusive
+        let filters = BidFilters {
+            from_slot: Some(base_slot + 1),
+            to_slot: Some(base_slot + 2),
+            ..Default::default()
+        };
+        let payloads = db_service.get_delivered_payloads(&filters, preferences.clone()).await?;
+        assert_eq!(block_hashes(&payloads), vec![delivered[2].clone(), delivered[1].clone()]);
+
+        // Slot range and builder combined
+        let filters = BidFilters {
+            from_slot: Some(base_slot + 1),
+            to_slot: Some(base_slot + 3),
+            builder_pubkey: Some(builder.clone()),
+            ..Default::default()
+        };
+        let payloads = db_service.get_delivered_payloads(&filters, preferences).await?;
+        assert_eq!(block_hashes(&payloads), vec![delivered[3].clone(), delivered[1].clone()]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_late_payloads() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -689,6 +847,114 @@ mod tests {
             existing_vec.insert(insert_index, value); // Insert the new value at the random index
         }
     }
-}
 
+    async fn store_bid(
+        db_service: &PostgresDatabaseService,
+        slot: u64,
+    ) -> Result<ByteVector<32>, Box<dyn std::error::Error>> {
+        store_bid_from_builder(db_service, slot, &Default::default()).await
+    }
 
+    async fn store_bid_from_builder(
+        db_service: &PostgresDatabaseService,
+        slot: u64,
+        builder_public_key: &BlsPublicKey,
+    ) -> Result<ByteVector<32>, Box<dyn std::error::Error>> {
+        let random_bytes: [u8; 32] = rand::thread_rng().gen();
+        let bid_trace = BidTrace {
+            slot,
+            block_hash: ByteVector::<32>::try_from(random_bytes.as_slice()).unwrap(),
+            builder_public_key: builder_public_key.clone(),
+            ..Default::default()
+        };
+        let mut signed_bid_submission = SignedBidSubmission::default();
+        match &mut signed_bid_submission {
+            SignedBidSubmission::Deneb(submission) => {
+                submission.message = bid_trace.clone();
+            }
+            SignedBidSubmission::Capella(submission) => {
+                submission.message = bid_trace.clone();
+            }
+        }
+
+        let mut submission_trace = SubmissionTrace::default();
+        submission_trace.receive = get_current_unix_time_in_nanos() as u64;
+
+        db_service
+            .store_block_submission(Arc::new(signed_bid_submission), Arc::new(submission_trace), 0)
+            .await?;
+        Ok(bid_trace.block_hash)
+    }
+
+    async fn deliver_bid(
+        db_service: &PostgresDatabaseService,
+        slot: u64,
+        block_hash: &ByteVector<32>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let execution_payload = ethereum_consensus::types::ExecutionPayload::Capella(
+            ethereum_consensus::capella::ExecutionPayload {
+                block_hash: block_hash.clone(),
+                ..Default::default()
+            },
+        );
+        let bid_trace = BidTrace { slot, block_hash: block_hash.clone(), ..Default::default() };
+        let payload_and_blobs = PayloadAndBlobs { execution_payload, blobs_bundle: None };
+
+        db_service
+            .save_delivered_payload(
+                &bid_trace,
+                Arc::new(payload_and_blobs),
+                &GetPayloadTrace::default(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn row_exists(
+        client: &deadpool_postgres::Client,
+        table: &str,
+        block_hash: &ByteVector<32>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let sql = format!("SELECT 1 FROM {table} WHERE block_hash = $1");
+        Ok(!client.query(&sql, &[&(block_hash.as_ref())]).await?.is_empty())
+    }
+
+    #[tokio::test]
+    async fn test_prune_records() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 1)?;
+        let client = setup_test_conn().await?;
+
+        // Slots well below the ones used by other tests, so pruning does not affect them
+        let old_slot = 10;
+        let recent_slot = 20;
+        let before_slot = 15;
+
+        let old_bid = store_bid(&db_service, old_slot).await?;
+        let recent_bid = store_bid(&db_service, recent_slot).await?;
+        let old_delivered = store_bid(&db_service, old_slot).await?;
+        deliver_bid(&db_service, old_slot, &old_delivered).await?;
+        let recent_delivered = store_bid(&db_service, recent_slot).await?;
+        deliver_bid(&db_service, recent_slot, &recent_delivered).await?;
+
+        // A chunk size of 1 forces the pruning over multiple transactions
+        let num_bids = db_service.prune_bids(before_slot, 1).await?;
+        assert!(num_bids >= 1);
+        assert!(!row_exists(&client, "block_submission", &old_bid).await?);
+        assert!(!row_exists(&client, "submission_trace", &old_bid).await?);
+        assert!(row_exists(&client, "block_submission", &recent_bid).await?);
+        // Delivered bids are kept until their payload expires
+        assert!(row_exists(&client, "block_submission", &old_delivered).await?);
+        assert!(row_exists(&client, "delivered_payload", &old_delivered).await?);
+
+        let num_payloads = db_service.prune_delivered_payloads(before_slot, 1).await?;
+        assert!(num_payloads >= 1);
+        assert!(!row_exists(&client, "delivered_payload", &old_delivered).await?);
+        assert!(!row_exists(&client, "payload_trace", &old_delivered).await?);
+        assert!(!row_exists(&client, "block_submission", &old_delivered).await?);
+        assert!(row_exists(&client, "delivered_payload", &recent_delivered).await?);
+        assert!(row_exists(&client, "block_submission", &recent_delivered).await?);
+        assert!(row_exists(&client, "block_submission", &recent_bid).await?);
+        Ok(())
+    }
+}
@@ -134,6 +134,15 @@ pub trait DatabaseService: Send + Sync + Clone {
         filters: &BidFilters,
     ) -> Result<Vec<BidSubmissionDocument>, DatabaseError>;
 
+    /// Returns up to `limit` bids received for `slot`, ordered by value descending.
+    /// If `cursor` is set, only bids ranked after the bid with that block hash are returned.
+    async fn get_bids_received(
+        &self,
+        slot: u64,
+        cursor: Option<Hash32>,
+        limit: u64,
+    ) -> Result<Vec<BidSubmissionDocument>, DatabaseError>;
+
     async fn get_delivered_payloads(
         &self,
         filters: &BidFilters,
@@ -181,4 +190,19 @@ pub trait DatabaseService: Send + Sync + Clone {
         &self,
         api_key: &str,
     ) -> Result<Option<String>, DatabaseError>;
+
+    /// Removes bids for slots before `before_slot` that were never delivered, along with their
+    /// traces. Rows are removed in transactions of at most `chunk_size` bids.
+    /// Returns the number of bids removed.
+    async fn prune_bids(&self, before_slot: u64, chunk_size: u64) -> Result<u64, DatabaseError>;
+
+    /// Removes delivered payloads for slots before `before_slot`, along with their bids,
+    /// transactions, withdrawals and traces. Rows are removed in transactions of at most
+    /// `chunk_size` payloads.
+    /// Returns the number of payloads removed.
+    async fn prune_delivered_payloads(
+        &self,
+        before_slot: u64,
+        chunk_size: u64,
+    ) -> Result<u64, DatabaseError>;
 }
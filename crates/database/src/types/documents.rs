@@ -30,7 +30,7 @@ pub struct TooLateGetPayloadDocument {
     pub payload_fetched: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeliveredPayloadDocument {
     pub bid_trace: BidTrace,
     pub block_number: u64,
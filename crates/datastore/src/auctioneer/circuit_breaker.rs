@@ -0,0 +1,838 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use ethereum_consensus::primitives::{BlsPublicKey, Hash32, U256};
+use helix_common::{
+    bid_submission::{v2::header_submission::SignedHeaderSubmission, BidTrace, SignedBidSubmission},
+    builder_info::BuilderInfo,
+    eth::SignedBuilderBid,
+    pending_block::PendingBlock,
+    signing::RelaySigningContext,
+    versioned_payload::PayloadAndBlobs,
+    CircuitBreakerConfig, ProposerInfo,
+};
+use helix_database::BuilderInfoDocument;
+use tokio_stream::Stream;
+use tracing::{info, warn};
+
+use crate::{error::AuctioneerError, types::SaveBidAndUpdateTopBidResponse, Auctioneer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed { consecutive_failures: u32 },
+    Open { until: Instant },
+    /// The cooldown elapsed. A single probe call is let through to test the backend.
+    HalfOpen { probe_started_at: Option<Instant> },
+}
+
+/// Tracks consecutive backend failures and fails fast once they exceed the configured threshold.
+///
+/// After the cooldown a single probe is let through. The breaker closes again if the probe
+/// succeeds and re-opens for another cooldown if it fails.
+pub struct CircuitBreaker {
+    state: Mutex<CircuitState>,
+    failure_threshold: u32,
+    cooldown: Duration,
+    call_timeout: Duration,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: &CircuitBreakerConfig) -> Self {
+        Self {
+            state: Mutex::new(CircuitState::Closed { consecutive_failures: 0 }),
+            failure_threshold: config.failure_threshold.max(1),
+            cooldown: Duration::from_millis(config.cooldown_ms),
+            call_timeout: Duration::from_millis(config.call_timeout_ms),
+        }
+    }
+
+    /// Returns false while the breaker is open and the cooldown has not elapsed yet.
+    pub fn is_available(&self) -> bool {
+        match *self.state.lock().unwrap() {
+            CircuitState::Open { until } => Instant::now() >= until,
+            _ => true,
+        }
+    }
+
+    /// Checks whether a call may go through. Moves an open breaker to half-open once the
+    /// cooldown elapsed.
+    fn acquire(&self) -> Result<(), AuctioneerError> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            CircuitState::Closed { .. } => Ok(()),
+            CircuitState::Open { until } if now < until => Err(AuctioneerError::CircuitOpen),
+            CircuitState::Open { .. } => {
+                *state = CircuitState::HalfOpen { probe_started_at: Some(now) };
+                Ok(())
+            }
+            CircuitState::HalfOpen { probe_started_at } => {
+                // A probe that was dropped before completing never reports back, so consider it
+                // lost once it has been running for longer than the call timeout.
+                let probe_in_flight = probe_started_at
+                    .map_or(false, |started_at| now.duration_since(started_at) < self.call_timeout);
+                if probe_in_flight {
+                    return Err(AuctioneerError::CircuitOpen);
+                }
+                *state = CircuitState::HalfOpen { probe_started_at: Some(now) };
+                Ok(())
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            CircuitState::HalfOpen { .. } => info!("auctioneer circuit breaker closed"),
+            CircuitState::Closed { .. } => {}
+            // Calls acquired before the breaker opened may still be completing. Only the probe
+            // may close it again.
+            CircuitState::Open { .. } => return,
+        }
+        *state = CircuitState::Closed { consecutive_failures: 0 };
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        let consecutive_failures = match *state {
+            CircuitState::Closed { consecutive_failures } => consecutive_failures + 1,
+            CircuitState::HalfOpen { .. } => self.failure_threshold,
+            // Calls acquired before the breaker opened may still be completing.
+            CircuitState::Open { .. } => return,
+        };
+
+        if consecutive_failures >= self.failure_threshold {
+            warn!(
+                consecutive_failures,
+                cooldown_ms = self.cooldown.as_millis() as u64,
+                "auctioneer circuit breaker opened"
+            );
+            *state = CircuitState::Open { until: Instant::now() + self.cooldown };
+        } else {
+            *state = CircuitState::Closed { consecutive_failures };
+        }
+    }
+
+    fn record<T>(&self, result: &Result<T, AuctioneerError>) {
+        match result {
+            Err(AuctioneerError::RedisError(_)) | Err(AuctioneerError::Timeout) => {
+                self.record_failure()
+            }
+            // Any other result means the backend responded.
+            _ => self.record_success(),
+        }
+    }
+}
+
+/// Wraps an `Auctioneer` with a `CircuitBreaker` and a per call timeout.
+///
+/// While the breaker is open all calls fail fast with `AuctioneerError::CircuitOpen` instead of
+/// waiting on an unhealthy datastore.
+#[derive(Clone)]
+pub struct CircuitBreakerAuctioneer<A: Auctioneer> {
+    inner: Arc<A>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl<A: Auctioneer> CircuitBreakerAuctioneer<A> {
+    pub fn new(inner: Arc<A>, config: &CircuitBreakerConfig) -> Self {
+        Self { inner, breaker: Arc::new(CircuitBreaker::new(config)) }
+    }
+
+    async fn call<T, F>(&self, fut: F) -> Result<T, AuctioneerError>
+    where
+        F: Future<Output = Result<T, AuctioneerError>>,
+    {
+        self.breaker.acquire()?;
+        let result = match tokio::time::timeout(self.breaker.call_timeout, fut).await {
+            Ok(result) => result,
+            Err(_) => Err(AuctioneerError::Timeout),
+        };
+        self.breaker.record(&result);
+        result
+    }
+}
+
+#[async_trait]
+impl<A: Auctioneer> Auctioneer for CircuitBreakerAuctioneer<A> {
+    async fn get_last_slot_delivered(&self) -> Result<Option<u64>, AuctioneerError> {
+        self.call(self.inner.get_last_slot_delivered()).await
+    }
+
+    async fn check_and_set_last_slot_and_hash_delivered(
+        &self,
+        slot: u64,
+        hash: &Hash32,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.check_and_set_last_slot_and_hash_delivered(slot, hash)).await
+    }
+
+    async fn get_best_bid(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<SignedBuilderBid>, AuctioneerError> {
+        self.call(self.inner.get_best_bid(slot, parent_hash, proposer_pub_key)).await
+    }
+
+    async fn get_best_bids(
+        &self,
+    ) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin> {
+        self.inner.get_best_bids().await
+    }
+
+    async fn save_execution_payload(
+        &self,
+        slot: u64,
+        proposer_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+        versioned_execution_payload: &PayloadAndBlobs,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_execution_payload(
+            slot,
+            proposer_pub_key,
+            block_hash,
+            versioned_execution_payload,
+        ))
+        .await
+    }
+
+    async fn get_execution_payload(
+        &self,
+        slot: u64,
+        proposer_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+    ) -> Result<Option<PayloadAndBlobs>, AuctioneerError> {
+        self.call(self.inner.get_execution_payload(slot, proposer_pub_key, block_hash)).await
+    }
+
+    async fn get_bid_trace(
+        &self,
+        slot: u64,
+        proposer_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+    ) -> Result<Option<BidTrace>, AuctioneerError> {
+        self.call(self.inner.get_bid_trace(slot, proposer_pub_key, block_hash)).await
+    }
+
+    async fn save_bid_trace(&self, bid_trace: &BidTrace) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_bid_trace(bid_trace)).await
+    }
+
+    async fn get_builder_latest_payload_received_at(
+        &self,
+        slot: u64,
+        builder_pub_key: &BlsPublicKey,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<u64>, AuctioneerError> {
+        self.call(self.inner.get_builder_latest_payload_received_at(
+            slot,
+            builder_pub_key,
+            parent_hash,
+            proposer_pub_key,
+        ))
+        .await
+    }
+
+    async fn save_builder_bid(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+        builder_pub_key: &BlsPublicKey,
+        received_at: u128,
+        builder_bid: &SignedBuilderBid,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_builder_bid(
+            slot,
+            parent_hash,
+            proposer_pub_key,
+            builder_pub_key,
+            received_at,
+            builder_bid,
+        ))
+        .await
+    }
+
+    async fn save_bid_and_update_top_bid(
+        &self,
+        submission: &SignedBidSubmission,
+        received_at: u128,
+        cancellations_enabled: bool,
+        floor_value: U256,
+        state: &mut SaveBidAndUpdateTopBidResponse,
+        signing_context: &RelaySigningContext,
+    ) -> Result<Option<(SignedBuilderBid, PayloadAndBlobs)>, AuctioneerError> {
+        self.call(self.inner.save_bid_and_update_top_bid(
+            submission,
+            received_at,
+            cancellations_enabled,
+            floor_value,
+            state,
+            signing_context,
+        ))
+        .await
+    }
+
+    async fn get_top_bid_value(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<U256>, AuctioneerError> {
+        self.call(self.inner.get_top_bid_value(slot, parent_hash, proposer_pub_key)).await
+    }
+
+    async fn get_builder_latest_value(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<Option<U256>, AuctioneerError> {
+        self.call(self.inner.get_builder_latest_value(
+            slot,
+            parent_hash,
+            proposer_pub_key,
+            builder_pub_key,
+        ))
+        .await
+    }
+
+    async fn get_floor_bid_value(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<U256>, AuctioneerError> {
+        self.call(self.inner.get_floor_bid_value(slot, parent_hash, proposer_pub_key)).await
+    }
+
+    async fn delete_builder_bid(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.delete_builder_bid(slot, parent_hash, proposer_pub_key, builder_pub_key))
+            .await
+    }
+
+    async fn get_builder_info(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<BuilderInfo, AuctioneerError> {
+        self.call(self.inner.get_builder_info(builder_pub_key)).await
+    }
+
+    async fn demote_builder(&self, builder_pub_key: &BlsPublicKey) -> Result<(), AuctioneerError> {
+        self.call(self.inner.demote_builder(builder_pub_key)).await
+    }
+
+    async fn update_builder_infos(
+        &self,
+        builder_infos: Vec<BuilderInfoDocument>,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.update_builder_infos(builder_infos)).await
+    }
+
+    async fn seen_or_insert_block_hash(
+        &self,
+        block_hash: &Hash32,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<bool, AuctioneerError> {
+        self.call(self.inner.seen_or_insert_block_hash(block_hash, slot, parent_hash, proposer_pub_key))
+            .await
+    }
+
+    async fn save_signed_builder_bid_and_update_top_bid(
+        &self,
+        builder_bid: &SignedBuilderBid,
+        bid_trace: &BidTrace,
+        received_at: u128,
+        cancellations_enabled: bool,
+        floor_value: U256,
+        state: &mut SaveBidAndUpdateTopBidResponse,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_signed_builder_bid_and_update_top_bid(
+            builder_bid,
+            bid_trace,
+            received_at,
+            cancellations_enabled,
+            floor_value,
+            state,
+        ))
+        .await
+    }
+
+    async fn save_header_submission_and_update_top_bid(
+        &self,
+        submission: &SignedHeaderSubmission,
+        received_at: u128,
+        cancellations_enabled: bool,
+        floor_value: U256,
+        state: &mut SaveBidAndUpdateTopBidResponse,
+        signing_context: &RelaySigningContext,
+    ) -> Result<Option<SignedBuilderBid>, AuctioneerError> {
+        self.call(self.inner.save_header_submission_and_update_top_bid(
+            submission,
+            received_at,
+            cancellations_enabled,
+            floor_value,
+            state,
+            signing_context,
+        ))
+        .await
+    }
+
+    async fn update_trusted_proposers(
+        &self,
+        proposer_whitelist: Vec<ProposerInfo>,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.update_trusted_proposers(proposer_whitelist)).await
+    }
+
+    async fn is_trusted_proposer(
+        &self,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<bool, AuctioneerError> {
+        self.call(self.inner.is_trusted_proposer(proposer_pub_key)).await
+    }
+
+    async fn get_pending_blocks(&self) -> Result<Vec<PendingBlock>, AuctioneerError> {
+        self.call(self.inner.get_pending_blocks()).await
+    }
+
+    async fn save_pending_block_header(
+        &self,
+        slot: u64,
+        builder_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+        timestamp_ms: u64,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_pending_block_header(slot, builder_pub_key, block_hash, timestamp_ms))
+            .await
+    }
+
+    async fn save_pending_block_payload(
+        &self,
+        slot: u64,
+        builder_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+        timestamp_ms: u64,
+    ) -> Result<(), AuctioneerError> {
+        self.call(self.inner.save_pending_block_payload(
+            slot,
+            builder_pub_key,
+            block_hash,
+            timestamp_ms,
+        ))
+        .await
+    }
+
+    async fn try_acquire_or_renew_leadership(&self, leader_id: &str) -> bool {
+        self.inner.try_acquire_or_renew_leadership(leader_id).await
+    }
+
+    fn is_available(&self) -> bool {
+        self.breaker.is_available() && self.inner.is_available()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{redis::error::RedisCacheError, MockAuctioneer};
+
+    /// Delegates to `MockAuctioneer` but fails `get_last_slot_delivered` while `failing` is set.
+    #[derive(Clone, Default)]
+    struct FlakyAuctioneer {
+        inner: MockAuctioneer,
+        failing: Arc<AtomicBool>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Auctioneer for FlakyAuctioneer {
+        async fn get_last_slot_delivered(&self) -> Result<Option<u64>, AuctioneerError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(AuctioneerError::RedisError(RedisCacheError::InternalError));
+            }
+            Ok(Some(1))
+        }
+
+        async fn check_and_set_last_slot_and_hash_delivered(
+            &self,
+            slot: u64,
+            hash: &Hash32,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.check_and_set_last_slot_and_hash_delivered(slot, hash).await
+        }
+
+        async fn get_best_bid(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<Option<SignedBuilderBid>, AuctioneerError> {
+            self.inner.get_best_bid(slot, parent_hash, proposer_pub_key).await
+        }
+
+        async fn get_best_bids(
+            &self,
+        ) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin> {
+            self.inner.get_best_bids().await
+        }
+
+        async fn save_execution_payload(
+            &self,
+            slot: u64,
+            proposer_pub_key: &BlsPublicKey,
+            block_hash: &Hash32,
+            versioned_execution_payload: &PayloadAndBlobs,
+        ) -> Result<(), AuctioneerError> {
+            self.inner
+                .save_execution_payload(slot, proposer_pub_key, block_hash, versioned_execution_payload)
+                .await
+        }
+
+        async fn get_execution_payload(
+            &self,
+            slot: u64,
+            proposer_pub_key: &BlsPublicKey,
+            block_hash: &Hash32,
+        ) -> Result<Option<PayloadAndBlobs>, AuctioneerError> {
+            self.inner.get_execution_payload(slot, proposer_pub_key, block_hash).await
+        }
+
+        async fn get_bid_trace(
+            &self,
+            slot: u64,
+            proposer_pub_key: &BlsPublicKey,
+            block_hash: &Hash32,
+        ) -> Result<Option<BidTrace>, AuctioneerError> {
+            self.inner.get_bid_trace(slot, proposer_pub_key, block_hash).await
+        }
+
+        async fn save_bid_trace(&self, bid_trace: &BidTrace) -> Result<(), AuctioneerError> {
+            self.inner.save_bid_trace(bid_trace).await
+        }
+
+        async fn get_builder_latest_payload_received_at(
+            &self,
+            slot: u64,
+            builder_pub_key: &BlsPublicKey,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<Option<u64>, AuctioneerError> {
+            self.inner
+                .get_builder_latest_payload_received_at(
+                    slot,
+                    builder_pub_key,
+                    parent_hash,
+                    proposer_pub_key,
+                )
+                .await
+        }
+
+        async fn save_builder_bid(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+            builder_pub_key: &BlsPublicKey,
+            received_at: u128,
+            builder_bid: &SignedBuilderBid,
+        ) -> Result<(), AuctioneerError> {
+            self.inner
+                .save_builder_bid(
+                    slot,
+                    parent_hash,
+                    proposer_pub_key,
+                    builder_pub_key,
+                    received_at,
+                    builder_bid,
+                )
+                .await
+        }
+
+        async fn save_bid_and_update_top_bid(
+            &self,
+            submission: &SignedBidSubmission,
+            received_at: u128,
+            cancellations_enabled: bool,
+            floor_value: U256,
+            state: &mut SaveBidAndUpdateTopBidResponse,
+            signing_context: &RelaySigningContext,
+        ) -> Result<Option<(SignedBuilderBid, PayloadAndBlobs)>, AuctioneerError> {
+            self.inner
+                .save_bid_and_update_top_bid(
+                    submission,
+                    received_at,
+                    cancellations_enabled,
+                    floor_value,
+                    state,
+                    signing_context,
+                )
+                .await
+        }
+
+        async fn get_top_bid_value(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<Option<U256>, AuctioneerError> {
+            self.inner.get_top_bid_value(slot, parent_hash, proposer_pub_key).await
+        }
+
+        async fn get_builder_latest_value(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+            builder_pub_key: &BlsPublicKey,
+        ) -> Result<Option<U256>, AuctioneerError> {
+            self.inner
+                .get_builder_latest_value(slot, parent_hash, proposer_pub_key, builder_pub_key)
+                .await
+        }
+
+        async fn get_floor_bid_value(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<Option<U256>, AuctioneerError> {
+            self.inner.get_floor_bid_value(slot, parent_hash, proposer_pub_key).await
+        }
+
+        async fn delete_builder_bid(
+            &self,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+            builder_pub_key: &BlsPublicKey,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.delete_builder_bid(slot, parent_hash, proposer_pub_key, builder_pub_key).await
+        }
+
+        async fn get_builder_info(
+            &self,
+            builder_pub_key: &BlsPublicKey,
+        ) -> Result<BuilderInfo, AuctioneerError> {
+            self.inner.get_builder_info(builder_pub_key).await
+        }
+
+        async fn demote_builder(
+            &self,
+            builder_pub_key: &BlsPublicKey,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.demote_builder(builder_pub_key).await
+        }
+
+        async fn update_builder_infos(
+            &self,
+            builder_infos: Vec<BuilderInfoDocument>,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.update_builder_infos(builder_infos).await
+        }
+
+        async fn seen_or_insert_block_hash(
+            &self,
+            block_hash: &Hash32,
+            slot: u64,
+            parent_hash: &Hash32,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<bool, AuctioneerError> {
+            self.inner
+                .seen_or_insert_block_hash(block_hash, slot, parent_hash, proposer_pub_key)
+                .await
+        }
+
+        async fn save_signed_builder_bid_and_update_top_bid(
+            &self,
+            builder_bid: &SignedBuilderBid,
+            bid_trace: &BidTrace,
+            received_at: u128,
+            cancellations_enabled: bool,
+            floor_value: U256,
+            state: &mut SaveBidAndUpdateTopBidResponse,
+        ) -> Result<(), AuctioneerError> {
+            self.inner
+                .save_signed_builder_bid_and_update_top_bid(
+                    builder_bid,
+                    bid_trace,
+                    received_at,
+                    cancellations_enabled,
+                    floor_value,
+                    state,
+                )
+                .await
+        }
+
+        async fn save_header_submission_and_update_top_bid(
+            &self,
+            submission: &SignedHeaderSubmission,
+            received_at: u128,
+            cancellations_enabled: bool,
+            floor_value: U256,
+            state: &mut SaveBidAndUpdateTopBidResponse,
+            signing_context: &RelaySigningContext,
+        ) -> Result<Option<SignedBuilderBid>, AuctioneerError> {
+            self.inner
+                .save_header_submission_and_update_top_bid(
+                    submission,
+                    received_at,
+                    cancellations_enabled,
+                    floor_value,
+                    state,
+                    signing_context,
+                )
+                .await
+        }
+
+        async fn update_trusted_proposers(
+            &self,
+            proposer_whitelist: Vec<ProposerInfo>,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.update_trusted_proposers(proposer_whitelist).await
+        }
+
+        async fn is_trusted_proposer(
+            &self,
+            proposer_pub_key: &BlsPublicKey,
+        ) -> Result<bool, AuctioneerError> {
+            self.inner.is_trusted_proposer(proposer_pub_key).await
+        }
+
+        async fn get_pending_blocks(&self) -> Result<Vec<PendingBlock>, AuctioneerError> {
+            self.inner.get_pending_blocks().await
+        }
+
+        async fn save_pending_block_header(
+            &self,
+            slot: u64,
+            builder_pub_key: &BlsPublicKey,
+            block_hash: &Hash32,
+            timestamp_ms: u64,
+        ) -> Result<(), AuctioneerError> {
+            self.inner.save_pending_block_header(slot, builder_pub_key, block_hash, timestamp_ms).await
+        }
+
+        async fn save_pending_block_payload(
+            &self,
+            slot: u64,
+            builder_pub_key: &BlsPublicKey,
+            block_hash: &Hash32,
+            timestamp_ms: u64,
+        ) -> Result<(), AuctioneerError> {
+            self.inner
+                .save_pending_block_payload(slot, builder_pub_key, block_hash, timestamp_ms)
+                .await
+        }
+
+        async fn try_acquire_or_renew_leadership(&self, leader_id: &str) -> bool {
+            self.inner.try_acquire_or_renew_leadership(leader_id).await
+        }
+    }
+
+    fn get_config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig { failure_threshold: 3, cooldown_ms: 100, call_timeout_ms: 50 }
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_after_consecutive_failures() {
+        let flaky = FlakyAuctioneer::default();
+        flaky.failing.store(true, Ordering::SeqCst);
+        let auctioneer = CircuitBreakerAuctioneer::new(Arc::new(flaky.clone()), &get_config());
+
+        for _ in 0..3 {
+            let result = auctioneer.get_last_slot_delivered().await;
+            assert!(matches!(result, Err(AuctioneerError::RedisError(_))));
+        }
+        assert!(!auctioneer.is_available());
+
+        // Fails fast without reaching the backend
+        let result = auctioneer.get_last_slot_delivered().await;
+        assert!(matches!(result, Err(AuctioneerError::CircuitOpen)));
+        assert_eq!(flaky.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_consecutive_failures() {
+        let flaky = FlakyAuctioneer::default();
+        let auctioneer = CircuitBreakerAuctioneer::new(Arc::new(flaky.clone()), &get_config());
+
+        for _ in 0..2 {
+            flaky.failing.store(true, Ordering::SeqCst);
+            assert!(auctioneer.get_last_slot_delivered().await.is_err());
+            assert!(auctioneer.get_last_slot_delivered().await.is_err());
+            flaky.failing.store(false, Ordering::SeqCst);
+            assert!(auctioneer.get_last_slot_delivered().await.is_ok());
+        }
+        assert!(auctioneer.is_available());
+    }
+
+    #[tokio::test]
+    async fn test_breaker_half_opens_after_cooldown() {
+        let flaky = FlakyAuctioneer::default();
+        flaky.failing.store(true, Ordering::SeqCst);
+        let auctioneer = CircuitBreakerAuctioneer::new(Arc::new(flaky.clone()), &get_config());
+
+        for _ in 0..3 {
+            let _ = auctioneer.get_last_slot_delivered().await;
+        }
+        assert!(!auctioneer.is_available());
+
+        // A failed probe re-opens the breaker for another cooldown
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(auctioneer.is_available());
+        let result = auctioneer.get_last_slot_delivered().await;
+        assert!(matches!(result, Err(AuctioneerError::RedisError(_))));
+        assert!(matches!(
+            auctioneer.get_last_slot_delivered().await,
+            Err(AuctioneerError::CircuitOpen)
+        ));
+        assert_eq!(flaky.calls.load(Ordering::SeqCst), 4);
+
+        // A successful probe closes it
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        flaky.failing.store(false, Ordering::SeqCst);
+        assert_eq!(auctioneer.get_last_slot_delivered().await.unwrap(), Some(1));
+        assert_eq!(auctioneer.get_last_slot_delivered().await.unwrap(), Some(1));
+        assert!(auctioneer.is_available());
+    }
+
+    #[test]
+    fn test_late_success_does_not_close_open_breaker() {
+        let breaker = CircuitBreaker::new(&get_config());
+
+        // A slow call goes through while the breaker is still closed
+        assert!(breaker.acquire().is_ok());
+
+        // Other calls fail in the meantime and open the breaker
+        for _ in 0..3 {
+            assert!(breaker.acquire().is_ok());
+            breaker.record_failure();
+        }
+        assert!(!breaker.is_available());
+
+        // The slow call succeeds during the cooldown
+        breaker.record_success();
+        assert!(!breaker.is_available());
+        assert!(matches!(breaker.acquire(), Err(AuctioneerError::CircuitOpen)));
+    }
+}
@@ -9,7 +9,8 @@ use helix_common::{
     }, eth::SignedBuilderBid, pending_block::PendingBlock, signing::RelaySigningContext, versioned_payload::PayloadAndBlobs, BuilderInfo, ProposerInfo
 };
 use helix_database::types::BuilderInfoDocument;
-use tokio_stream::Stream;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
 
 use crate::{error::AuctioneerError, types::SaveBidAndUpdateTopBidResponse, Auctioneer};
 
@@ -19,6 +20,11 @@ pub struct MockAuctioneer {
     pub builder_demoted: Arc<AtomicBool>,
     pub best_bid: Arc<Mutex<Option<SignedBuilderBid>>>,
     pub versioned_execution_payload: Arc<Mutex<Option<PayloadAndBlobs>>>,
+    pub bid_trace: Arc<Mutex<Option<BidTrace>>>,
+    /// When set, `get_best_bids` streams the updates sent on this channel
+    pub best_bids_tx: Arc<Mutex<Option<broadcast::Sender<Vec<u8>>>>>,
+    /// Builders whose bid was deleted through `delete_builder_bid`
+    pub deleted_builder_bids: Arc<Mutex<Vec<BlsPublicKey>>>,
 }
 
 impl MockAuctioneer {
@@ -28,6 +34,9 @@ impl MockAuctioneer {
             builder_demoted: Arc::new(AtomicBool::new(false)),
             best_bid: Arc::new(Mutex::new(None)),
             versioned_execution_payload: Arc::new(Mutex::new(None)),
+            bid_trace: Arc::new(Mutex::new(None)),
+            best_bids_tx: Arc::new(Mutex::new(None)),
+            deleted_builder_bids: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -60,6 +69,10 @@ impl Auctioneer for MockAuctioneer {
         Ok(self.best_bid.lock().unwrap().clone())
     }
     async fn get_best_bids(&self) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin> {
+        if let Some(tx) = self.best_bids_tx.lock().unwrap().as_ref() {
+            let stream = BroadcastStream::new(tx.subscribe()).map(|res| res.map_err(AuctioneerError::from));
+            return Box::new(stream);
+        }
         Box::new(tokio_stream::iter(vec![Ok(vec![0; 188]),Ok(vec![0; 188]),Ok(vec![0; 188])].into_iter()))
     }
 
@@ -87,7 +100,7 @@ impl Auctioneer for MockAuctioneer {
         _proposer_pub_key: &BlsPublicKey,
         _block_hash: &Hash32,
     ) -> Result<Option<BidTrace>, AuctioneerError> {
-        Ok(None)
+        Ok(self.bid_trace.lock().unwrap().clone())
     }
     async fn save_bid_trace(&self, _bid_trace: &BidTrace) -> Result<(), AuctioneerError> {
         Ok(())
@@ -158,8 +171,9 @@ impl Auctioneer for MockAuctioneer {
         _slot: u64,
         _parent_hash: &Hash32,
         _proposer_pub_key: &BlsPublicKey,
-        _builder_pub_key: &BlsPublicKey,
+        builder_pub_key: &BlsPublicKey,
     ) -> Result<(), AuctioneerError> {
+        self.deleted_builder_bids.lock().unwrap().push(builder_pub_key.clone());
         Ok(())
     }
 
@@ -167,7 +181,11 @@ impl Auctioneer for MockAuctioneer {
         &self,
         _builder_pub_key: &BlsPublicKey,
     ) -> Result<BuilderInfo, AuctioneerError> {
-        Ok(self.builder_info.clone().unwrap_or_default())
+        let mut builder_info = self.builder_info.clone().unwrap_or_default();
+        if self.builder_demoted.load(std::sync::atomic::Ordering::Relaxed) {
+            builder_info.is_optimistic = false;
+        }
+        Ok(builder_info)
     }
 
     async fn demote_builder(&self, _builder_pub_key: &BlsPublicKey) -> Result<(), AuctioneerError> {
@@ -1,5 +1,7 @@
+pub mod circuit_breaker;
 pub mod mock_auctioneer;
 pub mod traits;
 
+pub use circuit_breaker::*;
 pub use mock_auctioneer::*;
 pub use traits::*;
@@ -180,4 +180,9 @@ pub trait Auctioneer: Send + Sync + Clone {
     /// Try to acquire or renew leadership for the housekeeper.
     /// Returns: true if the housekeeper is the leader, false if it isn't.
     async fn try_acquire_or_renew_leadership(&self, leader_id: &str) -> bool;
+
+    /// Whether the auctioneer is currently able to serve requests.
+    fn is_available(&self) -> bool {
+        true
+    }
 }
@@ -3,6 +3,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use ethereum_consensus::{primitives::BlsPublicKey, ssz};
+use helix_utils::signer::SignerError;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
 use crate::redis::error::RedisCacheError;
@@ -47,6 +48,15 @@ pub enum AuctioneerError {
 
     #[error("ethereum consensus crypto error: {0}")]
     EthereumConsensusCryptoError(#[from] ethereum_consensus::crypto::Error),
+
+    #[error("signer error: {0}")]
+    SignerError(#[from] SignerError),
+
+    #[error("auctioneer circuit breaker is open")]
+    CircuitOpen,
+
+    #[error("auctioneer request timed out")]
+    Timeout,
 }
 
 impl IntoResponse for AuctioneerError {
@@ -97,6 +107,17 @@ impl IntoResponse for AuctioneerError {
                 (StatusCode::INTERNAL_SERVER_ERROR, format!("Ethereum consensus error: {err:?}"))
                     .into_response()
             }
+            AuctioneerError::SignerError(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("Signer error: {err}")).into_response()
+            }
+            AuctioneerError::CircuitOpen => {
+                (StatusCode::SERVICE_UNAVAILABLE, "Auctioneer circuit breaker is open".to_string())
+                    .into_response()
+            }
+            AuctioneerError::Timeout => {
+                (StatusCode::GATEWAY_TIMEOUT, "Auctioneer request timed out".to_string())
+                    .into_response()
+            }
         }
     }
 }
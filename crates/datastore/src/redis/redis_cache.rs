@@ -62,6 +62,33 @@ end
 return nil
 "#;
 
+/// Records the delivered slot and block hash unless a later slot, or a different block for the
+/// same slot, was already delivered. Runs as a single script so concurrent calls cannot both pass
+/// the check.
+const CHECK_AND_SET_LAST_DELIVERED_SCRIPT: &str = r#"
+local last_slot = redis.call('get', KEYS[1])
+if last_slot then
+    last_slot = tonumber(last_slot)
+    local slot = tonumber(ARGV[1])
+    if slot < last_slot then
+        return 'past_slot'
+    end
+    if slot == last_slot then
+        local last_hash = redis.call('get', KEYS[2])
+        if not last_hash then
+            return 'missing_hash'
+        end
+        if last_hash ~= ARGV[2] then
+            return 'another_payload'
+        end
+        return 'ok'
+    end
+end
+redis.call('set', KEYS[1], ARGV[1])
+redis.call('set', KEYS[2], ARGV[2])
+return 'ok'
+"#;
+
 #[derive(Clone)]
 pub struct RedisCache {
     pool: Pool,
@@ -508,10 +535,6 @@ impl RedisCache {
         Ok(())
     }
 
-    async fn get_last_hash_delivered(&self) -> Result<Option<Hash32>, RedisCacheError> {
-        self.get(LAST_HASH_DELIVERED_KEY).await
-    }
-
     /// 1) Update floor bid payload by copying from the best builder bid to floor bid.
     /// 2) Update floor bid value.
     async fn set_new_floor(
@@ -543,46 +566,24 @@ impl Auctioneer for RedisCache {
         slot: u64,
         hash: &Hash32,
     ) -> Result<(), AuctioneerError> {
-        let last_slot_delivered_res = self.get_last_slot_delivered().await?;
-
-        if let Some(last_slot_delivered) = last_slot_delivered_res {
-            if slot < last_slot_delivered {
-                return Err(AuctioneerError::PastSlotAlreadyDelivered);
-            }
-
-            if slot == last_slot_delivered {
-                let last_hash_delivered_res = self.get_last_hash_delivered().await?;
-
-                match last_hash_delivered_res {
-                    Some(last_hash_delivered) => {
-                        if *hash != last_hash_delivered {
-                            return Err(AuctioneerError::AnotherPayloadAlreadyDeliveredForSlot);
-                        }
-                    }
-                    None => return Err(AuctioneerError::UnexpectedValueType),
-                }
-                return Ok(());
-            }
-        }
-
         let mut conn = self.pool.get().await.map_err(RedisCacheError::from)?;
-        let mut pipe = redis::pipe();
-
-        // Add the SET commands to the pipeline
         let slot_value = serde_json::to_string(&slot).map_err(RedisCacheError::from)?;
         let hash_value =
             serde_json::to_string(&format!("{hash:?}")).map_err(RedisCacheError::from)?;
-        pipe.atomic()
-            .cmd("SET")
-            .arg(LAST_SLOT_DELIVERED_KEY)
-            .arg(slot_value)
-            .ignore()
-            .cmd("SET")
-            .arg(LAST_HASH_DELIVERED_KEY)
-            .arg(hash_value)
-            .ignore();
 
-        Ok(pipe.query_async(&mut conn).await.map_err(RedisCacheError::from)?)
+        let result: String = Script::new(CHECK_AND_SET_LAST_DELIVERED_SCRIPT)
+            .key(&[LAST_SLOT_DELIVERED_KEY, LAST_HASH_DELIVERED_KEY])
+            .arg(&[slot_value, hash_value])
+            .invoke_async(&mut conn)
+            .await
+            .map_err(RedisCacheError::from)?;
+
+        match result.as_str() {
+            "ok" => Ok(()),
+            "past_slot" => Err(AuctioneerError::PastSlotAlreadyDelivered),
+            "another_payload" => Err(AuctioneerError::AnotherPayloadAlreadyDeliveredForSlot),
+            _ => Err(AuctioneerError::UnexpectedValueType),
+        }
     }
 
     async fn get_best_bid(
@@ -742,9 +743,10 @@ impl Auctioneer for RedisCache {
         let builder_bid = SignedBuilderBid::from_submission(
             &mut cloned_submission,
             signing_context.public_key.clone(),
-            &signing_context.signing_key,
+            signing_context.signer.as_ref(),
             &signing_context.context,
-        )?;
+        )
+        .await?;
 
         // Save builder bid and update top bid/ floor keys if possible.
         self.save_signed_builder_bid_and_update_top_bid(
@@ -993,9 +995,10 @@ impl Auctioneer for RedisCache {
         let builder_bid = SignedBuilderBid::from_header_submission(
             submission,
             signing_context.public_key.clone(),
-            &signing_context.signing_key,
+            signing_context.signer.as_ref(),
             &signing_context.context,
-        )?;
+        )
+        .await?;
 
         // Save builder bid and update top bid/ floor keys if possible.
         self.save_signed_builder_bid_and_update_top_bid(
@@ -1549,6 +1552,30 @@ mod tests {
         assert!(matches!(set_result, Err(AuctioneerError::AnotherPayloadAlreadyDeliveredForSlot)));
     }
 
+    #[tokio::test]
+    async fn test_set_same_slot_different_hash_concurrently() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 42;
+        let block_hash1 = Hash32::try_from([4u8; 32].as_ref()).unwrap();
+        let block_hash2 = Hash32::try_from([5u8; 32].as_ref()).unwrap();
+
+        // Test: Only one of two racing deliveries for the same slot succeeds
+        let (result1, result2) = tokio::join!(
+            cache.check_and_set_last_slot_and_hash_delivered(slot, &block_hash1),
+            cache.check_and_set_last_slot_and_hash_delivered(slot, &block_hash2),
+        );
+        assert!(result1.is_ok() != result2.is_ok(), "exactly one delivery should succeed");
+        let delivered_hash = if result1.is_ok() { &block_hash1 } else { &block_hash2 };
+        for result in [result1, result2].into_iter().filter(|result| result.is_err()) {
+            assert!(matches!(result, Err(AuctioneerError::AnotherPayloadAlreadyDeliveredForSlot)));
+        }
+
+        // Test: The delivered block can still be requested again
+        assert!(cache.check_and_set_last_slot_and_hash_delivered(slot, delivered_hash).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_set_same_slot_no_hash() {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
@@ -1,6 +1,6 @@
 use std::{
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use std::collections::HashMap;
 
@@ -10,7 +10,10 @@ use ethereum_consensus::{
 use tokio::sync::{broadcast, mpsc};
 use tracing::{error, info, warn};
 
-use helix_beacon_client::types::{HeadEventData, PayloadAttributes, PayloadAttributesEvent};
+use helix_beacon_client::{
+    types::{HeadEventData, PayloadAttributes, PayloadAttributesEvent},
+    MultiBeaconClientTrait,
+};
 use helix_common::{api::builder_api::BuilderGetValidatorsResponseEntry, bellatrix::{List, Merkleized, Node}, chain_info::ChainInfo};
 use helix_database::DatabaseService;
 use helix_utils::{calculate_withdrawals_root, get_payload_attributes_key, has_reached_fork};
@@ -18,6 +21,12 @@ use helix_utils::{calculate_withdrawals_root, get_payload_attributes_key, has_re
 // Do not accept slots more than 60 seconds in the future
 const MAX_DISTANCE_FOR_FUTURE_SLOT: u64 = 60;
 
+const DUTIES_REFRESH_CHANNEL_SIZE: usize = 1;
+
+/// Payload attributes are handled inline with head events, so the parent gas limit lookup must
+/// not hold them up for long.
+const GET_BLOCK_GAS_LIMIT_TIMEOUT: Duration = Duration::from_millis(500);
+
 /// Payload for a new payload attribute event sent to subscribers.
 #[derive(Clone, Debug, Default)]
 pub struct PayloadAttributesUpdate {
@@ -25,6 +34,8 @@ pub struct PayloadAttributesUpdate {
     pub parent_hash: Bytes32,
     pub withdrawals_root: Option<Node>,
     pub payload_attributes: PayloadAttributes,
+    /// Gas limit of the parent block, `None` if it could not be fetched from the beacon client.
+    pub parent_gas_limit: Option<u64>,
 }
 
 /// Payload for head event updates sent to subscribers.
@@ -42,42 +53,62 @@ pub enum ChainUpdate {
 }
 
 /// Manages the update of head slots and the fetching of new proposer duties.
-pub struct ChainEventUpdater<D: DatabaseService> {
+pub struct ChainEventUpdater<D: DatabaseService, B: MultiBeaconClientTrait> {
     subscribers: Vec<mpsc::Sender<ChainUpdate>>,
 
     head_slot: u64,
     known_payload_attributes: HashMap<String, PayloadAttributesEvent>,
+    /// Gas limits by parent block root, with the proposal slot that fetched them.
+    parent_gas_limits: HashMap<String, (u64, u64)>,
 
     proposer_duties: Vec<BuilderGetValidatorsResponseEntry>,
 
     database: Arc<D>,
+    beacon_client: B,
     subscription_channel: mpsc::Receiver<mpsc::Sender<ChainUpdate>>,
     chain_info: Arc<ChainInfo>,
+
+    /// Requests to reload proposer duties from the db outside of the regular schedule
+    duties_refresh_sender: mpsc::Sender<()>,
+    duties_refresh_channel: mpsc::Receiver<()>,
 }
 
-impl<D: DatabaseService> ChainEventUpdater<D> {
+impl<D: DatabaseService, B: MultiBeaconClientTrait> ChainEventUpdater<D, B> {
     pub fn new_with_channel(
         database: Arc<D>,
+        beacon_client: B,
         subscription_channel: mpsc::Receiver<mpsc::Sender<ChainUpdate>>,
         chain_info: Arc<ChainInfo>,
     ) -> Self {
+        let (duties_refresh_sender, duties_refresh_channel) =
+            mpsc::channel(DUTIES_REFRESH_CHANNEL_SIZE);
         Self {
             subscribers: Vec::new(),
             head_slot: 0,
             known_payload_attributes: Default::default(),
+            parent_gas_limits: Default::default(),
             database,
+            beacon_client,
             subscription_channel,
             proposer_duties: Vec::new(),
             chain_info,
+            duties_refresh_sender,
+            duties_refresh_channel,
         }
     }
 
+    /// Returns a sender that triggers a reload of the proposer duties from the db.
+    pub fn duties_refresh_sender(&self) -> mpsc::Sender<()> {
+        self.duties_refresh_sender.clone()
+    }
+
     pub fn new(
         database: Arc<D>,
+        beacon_client: B,
         chain_info: Arc<ChainInfo>,
     ) -> (Self, mpsc::Sender<mpsc::Sender<ChainUpdate>>) {
         let (tx, rx) = mpsc::channel(200);
-        let updater = Self::new_with_channel(database, rx, chain_info);
+        let updater = Self::new_with_channel(database, beacon_client, rx, chain_info);
         (updater, tx)
     }
 
@@ -116,6 +147,9 @@ impl<D: DatabaseService> ChainEventUpdater<D> {
                 Some(sender) = self.subscription_channel.recv() => {
                     self.subscribers.push(sender);
                 }
+                Some(()) = self.duties_refresh_channel.recv() => {
+                    self.reload_proposer_duties().await;
+                }
             }
         }
     }
@@ -174,6 +208,35 @@ impl<D: DatabaseService> ChainEventUpdater<D> {
         self.send_update_to_subscribers(update).await;
     }
 
+    /// Reloads the proposer duties from the db and sends them to subscribers for the current head
+    /// slot.
+    async fn reload_proposer_duties(&mut self) {
+        let new_duties = match self.database.get_proposer_duties().await {
+            Ok(new_duties) => new_duties,
+            Err(err) => {
+                error!(error = %err, "Failed to get proposer duties from db");
+                return;
+            }
+        };
+
+        info!(head_slot = self.head_slot, num_duties = new_duties.len(), "Reloaded proposer duties");
+        self.proposer_duties = new_duties.clone();
+
+        // Subscribers pick up the duties with the first head event
+        if self.head_slot == 0 {
+            return;
+        }
+
+        let next_duty =
+            self.proposer_duties.iter().find(|duty| duty.slot == self.head_slot + 1).cloned();
+        let update = ChainUpdate::SlotUpdate(SlotUpdate {
+            slot: self.head_slot,
+            new_duties: Some(new_duties),
+            next_duty,
+        });
+        self.send_update_to_subscribers(update).await;
+    }
+
     // Handles a new payload attributes event
     async fn process_payload_attributes(&mut self, event: PayloadAttributesEvent) {
         // require new proposal slot in the future
@@ -206,16 +269,52 @@ impl<D: DatabaseService> ChainEventUpdater<D> {
             withdrawals_root = withdrawals_list.hash_tree_root().ok();
         }
 
+        let parent_gas_limit =
+            self.get_parent_gas_limit(&event.data.parent_block_root, event.data.proposal_slot).await;
+
         let update = ChainUpdate::PayloadAttributesUpdate(PayloadAttributesUpdate {
             slot: event.data.proposal_slot,
             parent_hash: event.data.parent_block_hash,
             withdrawals_root,
             payload_attributes: event.data.payload_attributes,
+            parent_gas_limit,
         });
 
         self.send_update_to_subscribers(update).await;
     }
 
+    /// Fetches the gas limit of `parent_block_root`, cached until `proposal_slot` is in the past.
+    ///
+    /// Returns `None` if the beacon client fails or doesn't respond within
+    /// `GET_BLOCK_GAS_LIMIT_TIMEOUT`. Failures are not cached, so the next payload attributes
+    /// event with the same parent tries again.
+    async fn get_parent_gas_limit(&mut self, parent_block_root: &str, proposal_slot: u64) -> Option<u64> {
+        self.parent_gas_limits.retain(|_, (slot, _)| *slot >= self.head_slot);
+        if let Some((_, gas_limit)) = self.parent_gas_limits.get(parent_block_root) {
+            return Some(*gas_limit);
+        }
+
+        let result = tokio::time::timeout(
+            GET_BLOCK_GAS_LIMIT_TIMEOUT,
+            self.beacon_client.get_block_gas_limit(parent_block_root),
+        )
+        .await;
+        match result {
+            Ok(Ok(gas_limit)) => {
+                self.parent_gas_limits.insert(parent_block_root.to_string(), (proposal_slot, gas_limit));
+                Some(gas_limit)
+            }
+            Ok(Err(err)) => {
+                warn!(parent_block_root = %parent_block_root, err = %err, "failed to fetch parent gas limit");
+                None
+            }
+            Err(_) => {
+                warn!(parent_block_root = %parent_block_root, "timed out fetching parent gas limit");
+                None
+            }
+        }
+    }
+
     async fn send_update_to_subscribers(&mut self, update: ChainUpdate) {
         // Store subscribers that should be unsubscribed
         let mut to_unsubscribe = Vec::new();
@@ -238,3 +337,147 @@ impl<D: DatabaseService> ChainEventUpdater<D> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helix_beacon_client::{
+        mock_multi_beacon_client::MockMultiBeaconClient, types::PayloadAttributesEventData,
+    };
+    use helix_database::MockDatabaseService;
+
+    fn get_head_event(slot: u64, block: &str) -> HeadEventData {
+        HeadEventData { slot, block: block.to_string(), state: String::new() }
+    }
+
+    #[tokio::test]
+    async fn test_reorged_head_does_not_regress_slot() {
+        let (mut updater, _) = ChainEventUpdater::new(
+            Arc::new(MockDatabaseService::default()),
+            MockMultiBeaconClient::default(),
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+        let (tx, mut rx) = mpsc::channel(10);
+        updater.subscribers.push(tx);
+
+        updater.process_head_event(get_head_event(101, "0x01")).await;
+        updater.process_head_event(get_head_event(102, "0x02")).await;
+
+        // The chain reorgs onto a competing block at an earlier slot
+        updater.process_head_event(get_head_event(101, "0x03")).await;
+        // A competing block for the current head slot
+        updater.process_head_event(get_head_event(102, "0x04")).await;
+
+        assert_eq!(updater.head_slot, 102);
+
+        let mut slots = Vec::new();
+        while let Ok(ChainUpdate::SlotUpdate(update)) = rx.try_recv() {
+            slots.push(update.slot);
+        }
+        assert_eq!(slots, vec![101, 102]);
+    }
+
+    #[tokio::test]
+    async fn test_reloaded_duties_are_sent_for_head_slot() {
+        let proposer_duties = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (mut updater, _) = ChainEventUpdater::new(
+            Arc::new(MockDatabaseService::new(Default::default(), proposer_duties.clone())),
+            MockMultiBeaconClient::default(),
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+        let (tx, mut rx) = mpsc::channel(10);
+        updater.subscribers.push(tx);
+
+        updater.process_head_event(get_head_event(101, "0x01")).await;
+        assert!(matches!(rx.try_recv(), Ok(ChainUpdate::SlotUpdate(SlotUpdate { new_duties: None, .. }))));
+
+        // Duties are updated in the db outside of the regular schedule
+        proposer_duties.lock().unwrap().push(BuilderGetValidatorsResponseEntry {
+            slot: 102,
+            validator_index: 1,
+            ..Default::default()
+        });
+        updater.reload_proposer_duties().await;
+
+        match rx.try_recv() {
+            Ok(ChainUpdate::SlotUpdate(update)) => {
+                assert_eq!(update.slot, 101);
+                assert_eq!(update.new_duties.unwrap().len(), 1);
+                assert_eq!(update.next_duty.unwrap().validator_index, 1);
+            }
+            update => panic!("expected slot update, got {update:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_payload_attributes_update_includes_parent_gas_limit() {
+        let (mut updater, _) = ChainEventUpdater::new(
+            Arc::new(MockDatabaseService::default()),
+            MockMultiBeaconClient::default(),
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+        let (tx, mut rx) = mpsc::channel(10);
+        updater.subscribers.push(tx);
+
+        let event = PayloadAttributesEvent {
+            version: "deneb".to_string(),
+            data: PayloadAttributesEventData { proposal_slot: 102, ..Default::default() },
+        };
+        updater.process_payload_attributes(event).await;
+
+        match rx.try_recv() {
+            Ok(ChainUpdate::PayloadAttributesUpdate(update)) => {
+                assert_eq!(update.parent_gas_limit, Some(30_000_000));
+            }
+            update => panic!("expected payload attributes update, got {update:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parent_gas_limit_is_cached_per_parent_root() {
+        let beacon_client = MockMultiBeaconClient::default();
+        let calls = beacon_client.block_gas_limit_calls.clone();
+        let (mut updater, _) = ChainEventUpdater::new(
+            Arc::new(MockDatabaseService::default()),
+            beacon_client,
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+
+        assert_eq!(updater.get_parent_gas_limit("0x01", 102).await, Some(30_000_000));
+        assert_eq!(updater.get_parent_gas_limit("0x01", 102).await, Some(30_000_000));
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 1);
+
+        // Dropped once the proposal slot is in the past
+        updater.head_slot = 103;
+        updater.get_parent_gas_limit("0x01", 104).await;
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_slow_parent_gas_limit_does_not_block_payload_attributes() {
+        let mut beacon_client = MockMultiBeaconClient::default();
+        beacon_client.block_gas_limit_delay = Some(Duration::from_secs(10));
+        let (mut updater, _) = ChainEventUpdater::new(
+            Arc::new(MockDatabaseService::default()),
+            beacon_client,
+            Arc::new(ChainInfo::for_mainnet()),
+        );
+        let (tx, mut rx) = mpsc::channel(10);
+        updater.subscribers.push(tx);
+
+        let event = PayloadAttributesEvent {
+            version: "deneb".to_string(),
+            data: PayloadAttributesEventData { proposal_slot: 102, ..Default::default() },
+        };
+        let start = std::time::Instant::now();
+        updater.process_payload_attributes(event).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        match rx.try_recv() {
+            Ok(ChainUpdate::PayloadAttributesUpdate(update)) => {
+                assert_eq!(update.parent_gas_limit, None);
+            }
+            update => panic!("expected payload attributes update, got {update:?}"),
+        }
+    }
+}
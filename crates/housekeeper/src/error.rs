@@ -13,6 +13,9 @@ pub enum HousekeeperError {
     #[error("already updating")]
     AlreadyUpdating(#[from] TryLockError),
 
+    #[error("no head slot has been processed yet")]
+    NoHeadSlot,
+
     #[error("database error. {0}")]
     DatabaseError(#[from] DatabaseError),
 
@@ -22,6 +22,7 @@ use helix_common::{
 };
 use helix_database::{error::DatabaseError, DatabaseService};
 use helix_datastore::Auctioneer;
+use serde::Serialize;
 
 use crate::error::HousekeeperError;
 use uuid::Uuid;
@@ -45,6 +46,16 @@ const MAX_DELAY_WITH_NO_V2_PAYLOAD_MS: u64 = 20_000;
 type SharedHousekeeper<Database, BeaconClient, Auctioneer> =
     Arc<Housekeeper<Database, BeaconClient, Auctioneer>>;
 
+/// Outcome of a proposer duties update.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProposerDutiesRefresh {
+    pub head_slot: u64,
+    /// Number of duties registered to the relay for the current and next epoch.
+    pub num_duties: usize,
+    /// Uri of the beacon client that served the duties.
+    pub beacon_client: String,
+}
+
 /// Housekeeper Service.
 ///
 /// Responsible for updating and managing known validators and proposer duties.
@@ -74,6 +85,9 @@ pub struct Housekeeper<
     refreshed_trusted_proposers_slot: Mutex<u64>,
     refresh_trusted_proposers_lock: Mutex<()>,
 
+    pruned_records_slot: Mutex<u64>,
+    prune_records_lock: Mutex<()>,
+
     leader_id: String,
 
     config: RelayConfig,
@@ -96,6 +110,8 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             re_sync_builder_info_lock: Mutex::new(()),
             refreshed_trusted_proposers_slot: Mutex::new(0),
             refresh_trusted_proposers_lock: Mutex::new(()),
+            pruned_records_slot: Mutex::new(0),
+            prune_records_lock: Mutex::new(()),
             leader_id: Uuid::new_v4().to_string(),
             config,
         })
@@ -179,6 +195,16 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             });
         }
 
+        // Spawn a task to asynchronously prune records past their retention.
+        if self.should_prune_records(head_slot).await {
+            let cloned_self = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = cloned_self.prune_records(head_slot).await {
+                    error!(err = %err, "failed to prune records");
+                }
+            });
+        }
+
         debug!(
             head_slot = head_slot,
             head_slot_pos = (head_slot % EPOCH_SLOTS) + 1,
@@ -371,11 +397,30 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
         head_slot_pos == 4 || head_slot_pos == 20
     }
 
+    /// Updates proposer duties for the current head slot without waiting for the next scheduled
+    /// update.
+    ///
+    /// Errors with `AlreadyUpdating` if an update is already in progress and with `NoHeadSlot` if
+    /// no head slot has been processed yet.
+    pub async fn refresh_proposer_duties(
+        self: &SharedHousekeeper<DB, BeaconClient, A>,
+    ) -> Result<ProposerDutiesRefresh, HousekeeperError> {
+        let head_slot = *self.head_slot.lock().await;
+        if head_slot == 0 {
+            return Err(HousekeeperError::NoHeadSlot);
+        }
+        info!(head_slot = head_slot, "refreshing proposer duties on demand");
+        self.update_proposer_duties(head_slot).await
+    }
+
     /// Update proposer duties for `head_slot` and `head_slot` + 1.
+    ///
+    /// Duties are fetched from the first beacon client that responds. If all beacon clients fail,
+    /// the previously stored duties are kept and the update is retried on the next slot.
     async fn update_proposer_duties(
         self: &SharedHousekeeper<DB, BeaconClient, A>,
         head_slot: u64,
-    ) -> Result<(), HousekeeperError> {
+    ) -> Result<ProposerDutiesRefresh, HousekeeperError> {
         // Only allow one update_proposer_duties task at a time.
         let _guard = self.proposer_duties_lock.try_lock()?;
 
@@ -383,13 +428,14 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
 
         info!(epoch_from = epoch, epoch_to = epoch + 1, "Housekeeper::update_proposer_duties",);
 
-        let proposer_duties = match self.fetch_duties(epoch).await {
-            Ok(proposer_duties) => proposer_duties,
+        let (beacon_client, proposer_duties) = match self.fetch_duties(epoch).await {
+            Ok(fetched) => fetched,
             Err(err) => {
                 error!(err = %err, "failed to fetch proposer duties");
                 return Err(HousekeeperError::BeaconClientError(err));
             }
         };
+        let proposer_duties = normalize_proposer_duties(proposer_duties, head_slot);
 
         // Check if signed validator registrations exist for each proposer duty
         let pub_keys: Vec<BlsPublicKey> =
@@ -403,23 +449,27 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
                 }
             };
 
-        if signed_validator_registrations.is_empty() {
+        let stored = if signed_validator_registrations.is_empty() {
             warn!("No signed validator registrations found for proposer duties");
+            Ok(0)
         } else {
-            match self
-                .format_and_store_duties(proposer_duties, signed_validator_registrations)
-                .await
-            {
-                Ok(num_duties) => {
-                    info!(epoch_from = epoch, num_duties = num_duties, "updated proposer duties")
-                }
-                Err(err) => error!(err = %err, "failed to update proposer duties"),
-            }
-        }
+            self.format_and_store_duties(proposer_duties, signed_validator_registrations).await
+        };
 
         *self.proposer_duties_slot.lock().await = head_slot;
 
-        Ok(())
+        let num_duties = match stored {
+            Ok(num_duties) => {
+                info!(epoch_from = epoch, num_duties = num_duties, "updated proposer duties");
+                num_duties
+            }
+            Err(err) => {
+                error!(err = %err, "failed to update proposer duties");
+                return Err(HousekeeperError::DatabaseError(err));
+            }
+        };
+
+        Ok(ProposerDutiesRefresh { head_slot, num_duties, beacon_client })
     }
 
     /// Format and store proposer duties
@@ -520,16 +570,73 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
         Ok(())
     }
 
-    /// Fetch proposer duties for the given epoch and epoch + 1.
+    /// Determine if records should be pruned for the given slot.
+    ///
+    /// Records are pruned if any retention is configured and at least an epoch has passed since
+    /// they were last pruned (`pruned_records_slot`), so missed slots can't skip a run.
+    pub(crate) async fn should_prune_records(&self, head_slot: u64) -> bool {
+        let retention = &self.config.retention;
+        let has_retention = retention.bid_retention_slots.is_some() ||
+            retention.delivered_payload_retention_slots.is_some();
+        let pruned_records_slot = *self.pruned_records_slot.lock().await;
+        has_retention && head_slot.saturating_sub(pruned_records_slot) >= EPOCH_SLOTS
+    }
+
+    /// Remove bids and delivered payloads older than their configured retention.
+    ///
+    /// Losing bids and delivered payloads are pruned separately so payloads can be kept for longer.
+    /// Updates `pruned_records_slot` to the current `head_slot` once both are pruned.
+    pub(crate) async fn prune_records(
+        self: &SharedHousekeeper<DB, BeaconClient, A>,
+        head_slot: u64,
+    ) -> Result<(), HousekeeperError> {
+        let _guard = self.prune_records_lock.try_lock()?;
+        let retention = &self.config.retention;
+
+        if let Some(retention_slots) = retention.bid_retention_slots {
+            let before_slot = head_slot.saturating_sub(retention_slots);
+            let start = Instant::now();
+            let num_bids = self.db.prune_bids(before_slot, retention.prune_chunk_size).await?;
+            info!(
+                head_slot = head_slot,
+                before_slot = before_slot,
+                num_bids = num_bids,
+                prune_latency_ms = start.elapsed().as_millis(),
+                "pruned bids"
+            );
+        }
+
+        if let Some(retention_slots) = retention.delivered_payload_retention_slots {
+            let before_slot = head_slot.saturating_sub(retention_slots);
+            let start = Instant::now();
+            let num_payloads =
+                self.db.prune_delivered_payloads(before_slot, retention.prune_chunk_size).await?;
+            info!(
+                head_slot = head_slot,
+                before_slot = before_slot,
+                num_payloads = num_payloads,
+                prune_latency_ms = start.elapsed().as_millis(),
+                "pruned delivered payloads"
+            );
+        }
+
+        *self.pruned_records_slot.lock().await = head_slot;
+
+        Ok(())
+    }
+
+    /// Fetch proposer duties for the given epoch and epoch + 1, along with the uri of the beacon
+    /// client that served the current epoch.
     ///
     /// This function will error if it cannot fetch the duties for the current epoch
     /// but will continue if it fails to fetch epoch + 1.
     async fn fetch_duties(
         self: &SharedHousekeeper<DB, BeaconClient, A>,
         epoch: u64,
-    ) -> Result<Vec<ProposerDuty>, BeaconClientError> {
+    ) -> Result<(String, Vec<ProposerDuty>), BeaconClientError> {
         // Fetch duties for current epoch
-        let (_, mut proposer_duties) = self.beacon_client.get_proposer_duties(epoch).await?;
+        let (beacon_client, _, mut proposer_duties) =
+            self.beacon_client.get_proposer_duties_with_source(epoch).await?;
 
         // Fetch duties for next epoch
         match self.beacon_client.get_proposer_duties(epoch + 1).await {
@@ -537,7 +644,7 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             Err(err) => error!(err = %err, "Error fetching next proposer duties"),
         }
 
-        Ok(proposer_duties)
+        Ok((beacon_client, proposer_duties))
     }
 
     /// Fetch validator registrations for `pub_keys` from database.
@@ -551,6 +658,42 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
     }
 }
 
+/// Sorts proposer duties by slot, dropping duplicate slots, slots below `head_slot` and slots past
+/// the epoch after the current one.
+///
+/// The beacon client serves every duty of the current epoch, so dropping those before `head_slot`
+/// is expected. Unsorted duties, duplicates and slots past the next epoch should not happen, a
+/// warning is logged if they do.
+pub(crate) fn normalize_proposer_duties(
+    mut proposer_duties: Vec<ProposerDuty>,
+    head_slot: u64,
+) -> Vec<ProposerDuty> {
+    let num_received = proposer_duties.len();
+    let is_sorted = proposer_duties.windows(2).all(|duties| duties[0].slot <= duties[1].slot);
+    let end_slot = (head_slot / EPOCH_SLOTS + 2) * EPOCH_SLOTS;
+
+    // Stable sort, so the first duty received for a slot is kept
+    proposer_duties.sort_by_key(|duty| duty.slot);
+    proposer_duties.dedup_by_key(|duty| duty.slot);
+    let num_unique = proposer_duties.len();
+    proposer_duties.retain(|duty| duty.slot < end_slot);
+    let num_beyond_end_slot = num_unique - proposer_duties.len();
+    proposer_duties.retain(|duty| duty.slot >= head_slot);
+
+    if !is_sorted || num_unique != num_received || num_beyond_end_slot > 0 {
+        warn!(
+            head_slot = head_slot,
+            is_sorted = is_sorted,
+            num_received = num_received,
+            num_duplicates = num_received - num_unique,
+            num_beyond_end_slot = num_beyond_end_slot,
+            "normalized invalid proposer duties",
+        );
+    }
+
+    proposer_duties
+}
+
 /// Calculates the delay in submission of the payload after a header.
 ///
 /// Returns true if the payload was received over 2 seconds after the header or if the payload was
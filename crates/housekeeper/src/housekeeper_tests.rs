@@ -10,13 +10,19 @@ mod housekeeper_tests {
     };
 
     // ++++ IMPORTS ++++
-    use crate::housekeeper::{Housekeeper, SLEEP_DURATION_BEFORE_REFRESHING_VALIDATORS};
+    use crate::housekeeper::{
+        normalize_proposer_duties, Housekeeper, SLEEP_DURATION_BEFORE_REFRESHING_VALIDATORS,
+    };
     use helix_beacon_client::{
         mock_multi_beacon_client::MockMultiBeaconClient, MultiBeaconClientTrait,
     };
-    use helix_common::{api::builder_api::BuilderGetValidatorsResponseEntry, RelayConfig, ValidatorSummary};
+    use helix_common::{
+        api::builder_api::BuilderGetValidatorsResponseEntry, ProposerDuty, RelayConfig,
+        ValidatorSummary,
+    };
     use helix_database::MockDatabaseService;
     use helix_datastore::MockAuctioneer;
+    use reth_primitives::constants::EPOCH_SLOTS;
     use tokio::{sync::broadcast, task};
 
     const HEAD_EVENT_CHANNEL_SIZE: usize = 100;
@@ -64,6 +70,18 @@ mod housekeeper_tests {
         });
     }
 
+    fn get_proposer_duties(slots: &[u64]) -> Vec<ProposerDuty> {
+        slots
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| ProposerDuty {
+                public_key: Default::default(),
+                validator_index: i,
+                slot: *slot,
+            })
+            .collect()
+    }
+
     struct HelperVars {
         pub housekeeper:
             Arc<Housekeeper<MockDatabaseService, MockMultiBeaconClient, MockAuctioneer>>,
@@ -141,4 +159,54 @@ mod housekeeper_tests {
 
         assert!(vars.state_validators_has_been_read.load(std::sync::atomic::Ordering::Relaxed));
     }
+
+    #[test]
+    fn test_unsorted_proposer_duties_are_sorted_and_deduplicated() {
+        let duties = get_proposer_duties(&[67, 64, 70, 64, 65]);
+
+        let normalized = normalize_proposer_duties(duties, 64);
+
+        let slots: Vec<u64> = normalized.iter().map(|duty| duty.slot).collect();
+        assert_eq!(slots, vec![64, 65, 67, 70]);
+        // The first duty received for a duplicated slot is kept
+        assert_eq!(normalized[0].validator_index, 1);
+    }
+
+    #[test]
+    fn test_proposer_duties_are_truncated_to_two_epochs() {
+        // Head slot is in epoch 2, so only slots up to the end of epoch 3 are kept
+        let duties = get_proposer_duties(&[60, 70, 80, 127, 128, 200]);
+
+        let normalized = normalize_proposer_duties(duties, 70);
+
+        let slots: Vec<u64> = normalized.iter().map(|duty| duty.slot).collect();
+        assert_eq!(slots, vec![70, 80, 127]);
+    }
+
+    #[tokio::test]
+    async fn test_records_are_pruned_once_an_epoch_has_passed() {
+        let mut config = RelayConfig::default();
+        config.retention.bid_retention_slots = Some(100);
+        let housekeeper = Housekeeper::new(
+            Arc::new(MockDatabaseService::default()),
+            MockMultiBeaconClient::default(),
+            MockAuctioneer::new(),
+            config,
+        );
+
+        // Slots not at an epoch boundary still trigger a prune, e.g. after missed slots
+        assert!(housekeeper.should_prune_records(EPOCH_SLOTS + 5).await);
+        housekeeper.prune_records(EPOCH_SLOTS + 5).await.unwrap();
+
+        assert!(!housekeeper.should_prune_records(EPOCH_SLOTS + 6).await);
+        assert!(!housekeeper.should_prune_records(2 * EPOCH_SLOTS).await);
+        assert!(housekeeper.should_prune_records(2 * EPOCH_SLOTS + 5).await);
+        assert!(housekeeper.should_prune_records(2 * EPOCH_SLOTS + 9).await);
+    }
+
+    #[tokio::test]
+    async fn test_records_are_not_pruned_without_retention() {
+        let vars = get_housekeeper();
+        assert!(!vars.housekeeper.should_prune_records(10 * EPOCH_SLOTS).await);
+    }
 }
@@ -6,4 +6,4 @@ pub mod housekeeper_tests;
 pub use chain_event_updater::{
     ChainEventUpdater, ChainUpdate, PayloadAttributesUpdate, SlotUpdate,
 };
-pub use housekeeper::Housekeeper;
+pub use housekeeper::{Housekeeper, ProposerDutiesRefresh};
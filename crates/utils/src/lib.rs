@@ -9,6 +9,7 @@ use reth_primitives::{proofs, Address};
 
 pub mod request_encoding;
 pub mod serde;
+pub mod signer;
 pub mod signing;
 
 pub fn has_reached_fork(slot: u64, fork_epoch: u64) -> bool {
@@ -0,0 +1,278 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use ethereum_consensus::{
+    builder::ValidatorRegistration,
+    crypto::SecretKey,
+    primitives::{BlsPublicKey, BlsSignature, Root},
+    ssz::prelude::*,
+    state_transition::Context,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::signing::compute_builder_signing_root;
+
+pub const DEFAULT_REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("ethereum consensus error: {0}")]
+    EthereumConsensusError(#[from] ethereum_consensus::Error),
+
+    #[error("failed to serialize message: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("remote signer request timed out")]
+    Timeout,
+
+    #[error("remote signer request error: {0}")]
+    RequestError(#[from] reqwest::Error),
+
+    #[error("remote signer returned status {status}: {body}")]
+    UnexpectedStatus { status: u16, body: String },
+}
+
+/// A message signed over the builder domain.
+pub trait BuilderMessage: Merkleized + Serialize {
+    /// Web3Signer sign request `type`. The message is sent under the lowercased type.
+    const SIGN_TYPE: &'static str;
+}
+
+impl BuilderMessage for ValidatorRegistration {
+    const SIGN_TYPE: &'static str = "VALIDATOR_REGISTRATION";
+}
+
+/// A builder domain message to sign, along with its signing root.
+pub struct SigningRequest {
+    pub sign_type: &'static str,
+    pub message: serde_json::Value,
+    pub signing_root: Root,
+}
+
+/// Signs messages on behalf of the relay.
+#[async_trait]
+pub trait RelaySigner: Send + Sync {
+    /// The public key of the key used for signing.
+    fn public_key(&self) -> &BlsPublicKey;
+
+    /// Signs a builder domain message.
+    async fn sign(&self, request: SigningRequest) -> Result<BlsSignature, SignerError>;
+}
+
+/// Signs a builder API message (e.g. a `BuilderBid`) with the given signer.
+pub async fn sign_builder_message_with<T: BuilderMessage>(
+    message: &mut T,
+    signer: &dyn RelaySigner,
+    context: &Context,
+) -> Result<BlsSignature, SignerError> {
+    let signing_root = compute_builder_signing_root(message, context)?;
+    let request = SigningRequest {
+        sign_type: T::SIGN_TYPE,
+        message: serde_json::to_value(&*message)?,
+        signing_root,
+    };
+    signer.sign(request).await
+}
+
+/// Signs with a BLS secret key held in memory.
+#[derive(Clone, Default)]
+pub struct LocalSigner {
+    signing_key: SecretKey,
+    public_key: BlsPublicKey,
+}
+
+impl LocalSigner {
+    pub fn new(signing_key: SecretKey) -> Self {
+        let public_key = signing_key.public_key();
+        Self { signing_key, public_key }
+    }
+}
+
+#[async_trait]
+impl RelaySigner for LocalSigner {
+    fn public_key(&self) -> &BlsPublicKey {
+        &self.public_key
+    }
+
+    async fn sign(&self, request: SigningRequest) -> Result<BlsSignature, SignerError> {
+        Ok(self.signing_key.sign(request.signing_root.as_ref()))
+    }
+}
+
+#[derive(Deserialize)]
+struct RemoteSignResponse {
+    signature: BlsSignature,
+}
+
+/// Signs through the Web3Signer eth2 signing API.
+///
+/// Posts a typed sign request to `{url}/api/v1/eth2/sign/{public_key}`, e.g.
+/// `{"type": "VALIDATOR_REGISTRATION", "signingRoot": "0x..", "validator_registration": {..}}`,
+/// and expects `{"signature": "0x.."}` back. The signer recomputes the signing root from the
+/// message and rejects the request if it differs. Builder domain messages are signed over the
+/// genesis fork version, so no `fork_info` is sent.
+///
+/// Bids are sent with type `BUILDER_BID` under `builder_bid`, which the signer must support.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    public_key: BlsPublicKey,
+    timeout: Duration,
+}
+
+impl RemoteSigner {
+    pub fn new(url: String, public_key: BlsPublicKey, timeout: Duration) -> Self {
+        Self { client: reqwest::Client::new(), url, public_key, timeout }
+    }
+}
+
+#[async_trait]
+impl RelaySigner for RemoteSigner {
+    fn public_key(&self) -> &BlsPublicKey {
+        &self.public_key
+    }
+
+    async fn sign(&self, request: SigningRequest) -> Result<BlsSignature, SignerError> {
+        let url = format!("{}/api/v1/eth2/sign/{:?}", self.url, self.public_key);
+        let mut body = serde_json::json!({
+            "type": request.sign_type,
+            "signingRoot": request.signing_root,
+        });
+        body[request.sign_type.to_lowercase()] = request.message;
+
+        let response = self
+            .client
+            .post(url)
+            .header("accept", "application/json")
+            .timeout(self.timeout)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|err| if err.is_timeout() { SignerError::Timeout } else { err.into() })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(SignerError::UnexpectedStatus { status: status.as_u16(), body });
+        }
+
+        let response: RemoteSignResponse = response
+            .json()
+            .await
+            .map_err(|err| if err.is_timeout() { SignerError::Timeout } else { err.into() })?;
+
+        Ok(response.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    use crate::signing::{sign_builder_message, verify_signed_builder_message};
+
+    fn get_signing_key() -> SecretKey {
+        SecretKey::try_from(
+            "0x123456789573772b8ffd9deddb468017a73cae08451ef05e604194705a1bade8".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn get_signing_request() -> SigningRequest {
+        SigningRequest {
+            sign_type: ValidatorRegistration::SIGN_TYPE,
+            message: serde_json::to_value(ValidatorRegistration::default()).unwrap(),
+            signing_root: Root::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_matches_local_signature() {
+        let signing_key = get_signing_key();
+        let public_key = signing_key.public_key();
+        let context = Context::for_mainnet();
+        let mut message = ValidatorRegistration {
+            gas_limit: 30_000_000,
+            timestamp: 1_700_000_000,
+            public_key: public_key.clone(),
+            ..Default::default()
+        };
+        let expected_signature =
+            sign_builder_message(&mut message, &signing_key, &context).unwrap();
+
+        // A typed Web3Signer request carrying the message and its signing root
+        let signing_root = compute_builder_signing_root(&mut message, &context).unwrap();
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", format!("/api/v1/eth2/sign/{public_key:?}").as_str())
+            .match_header("content-type", "application/json")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "type": "VALIDATOR_REGISTRATION",
+                "signingRoot": format!("{signing_root:?}"),
+                "validator_registration": {
+                    "fee_recipient": format!("{:?}", message.fee_recipient),
+                    "gas_limit": message.gas_limit.to_string(),
+                    "timestamp": message.timestamp.to_string(),
+                    "pubkey": format!("{public_key:?}"),
+                },
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "signature": expected_signature }).to_string())
+            .create_async()
+            .await;
+
+        let signer =
+            RemoteSigner::new(server.url(), public_key.clone(), DEFAULT_REMOTE_SIGNER_TIMEOUT);
+        let signature = sign_builder_message_with(&mut message, &signer, &context).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(signature, expected_signature);
+        verify_signed_builder_message(&mut message, &signature, &public_key, &context).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_error_status() {
+        let public_key = get_signing_key().public_key();
+
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("POST", format!("/api/v1/eth2/sign/{public_key:?}").as_str())
+            .with_status(404)
+            .with_body("Public Key not found")
+            .create_async()
+            .await;
+
+        let signer = RemoteSigner::new(server.url(), public_key, DEFAULT_REMOTE_SIGNER_TIMEOUT);
+        let result = signer.sign(get_signing_request()).await;
+
+        assert!(matches!(result, Err(SignerError::UnexpectedStatus { status: 404, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_timeout() {
+        // Accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let signer =
+            RemoteSigner::new(url, get_signing_key().public_key(), Duration::from_millis(50));
+        let result = signer.sign(get_signing_request()).await;
+
+        assert!(matches!(result, Err(SignerError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn test_local_signer() {
+        let signing_key = get_signing_key();
+        let context = Context::for_mainnet();
+        let mut message = ValidatorRegistration::default();
+
+        let signer = LocalSigner::new(signing_key.clone());
+        let signature = sign_builder_message_with(&mut message, &signer, &context).await.unwrap();
+
+        assert_eq!(signature, sign_builder_message(&mut message, &signing_key, &context).unwrap());
+    }
+}
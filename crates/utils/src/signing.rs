@@ -1,4 +1,5 @@
 use ethereum_consensus::{
+    builder::SignedValidatorRegistration,
     crypto::SecretKey,
     domains::DomainType,
     phase0::mainnet::compute_domain,
@@ -40,6 +41,21 @@ pub fn verify_signed_builder_message<T: Merkleized>(
     Ok(())
 }
 
+/// Verifies a validator registration. Registrations are always signed over the application
+/// builder domain, so callers cannot verify them against the wrong one.
+pub fn verify_validator_registration(
+    registration: &mut SignedValidatorRegistration,
+    context: &Context,
+) -> Result<(), Error> {
+    let public_key = registration.message.public_key.clone();
+    verify_signed_builder_message(
+        &mut registration.message,
+        &registration.signature,
+        &public_key,
+        context,
+    )
+}
+
 pub fn compute_consensus_signing_root<T: Merkleized>(
     data: &mut T,
     slot: Slot,
@@ -78,3 +94,47 @@ pub fn compute_builder_domain(context: &Context) -> Result<Domain, Error> {
     let domain_type = DomainType::ApplicationBuilder;
     compute_domain(domain_type, None, None, context)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_consensus::builder::ValidatorRegistration;
+
+    fn get_signing_key() -> SecretKey {
+        SecretKey::try_from(
+            "0x123456789573772b8ffd9deddb468017a73cae08451ef05e604194705a1bade8".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn get_registration(domain: Domain) -> SignedValidatorRegistration {
+        let signing_key = get_signing_key();
+        let mut message =
+            ValidatorRegistration { public_key: signing_key.public_key(), ..Default::default() };
+        let signature = sign_with_domain(&mut message, &signing_key, domain).unwrap();
+        SignedValidatorRegistration { message, signature }
+    }
+
+    #[test]
+    fn test_verify_validator_registration_builder_domain() {
+        let context = Context::for_mainnet();
+        let mut registration = get_registration(compute_builder_domain(&context).unwrap());
+
+        assert!(verify_validator_registration(&mut registration, &context).is_ok());
+    }
+
+    #[test]
+    fn test_verify_validator_registration_wrong_domain() {
+        let context = Context::for_mainnet();
+
+        let proposer_domain =
+            compute_domain(DomainType::BeaconProposer, None, None, &context).unwrap();
+        let mut registration = get_registration(proposer_domain);
+        assert!(verify_validator_registration(&mut registration, &context).is_err());
+
+        // The builder domain is network specific
+        let sepolia_domain = compute_builder_domain(&Context::for_sepolia()).unwrap();
+        let mut registration = get_registration(sepolia_domain);
+        assert!(verify_validator_registration(&mut registration, &context).is_err());
+    }
+}